@@ -0,0 +1,128 @@
+// Run with `cargo bench --bench scalar_mul`. Times each scalar multiplication
+// method and fixed-base tables on the README's p = 1021 curve and on curves
+// over 61 and 63-bit primes.
+use std::time::Instant;
+
+use rusty_diffie_hellman::{
+    ec::{ec_point::ECPoint, fixed_base::FixedBaseTable, scalar_mul::ScalarMulMethod},
+    felt::felt::Felt,
+};
+
+// First point with x >= 2 on y^2 = x^3 - 3x + b
+fn point(modulus: u64, b: u64) -> ECPoint {
+    let a = -Felt::new(3, modulus);
+    let b = Felt::new(b, modulus);
+    (2..)
+        .find_map(|x| {
+            let x = Felt::new(x, modulus);
+            let y = (x.pow(3) + a * x + b).sqrt()?;
+            ECPoint::new(x, y, a, b).ok()
+        })
+        .unwrap()
+}
+
+// Deterministic scalars with the full bit length of the field
+fn scalars(count: usize, modulus: u64) -> Vec<u64> {
+    let mut state = 0x9e3779b97f4a7c15_u64;
+    (0..count)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state % modulus) | (1 << (63 - modulus.leading_zeros()))
+        })
+        .collect()
+}
+
+// Naive summation, Straus and Pippenger for growing numbers of terms
+fn multi_scalar_mul(p: ECPoint) {
+    println!("Multi-scalar multiplication on p = {}", p.a().modulus());
+    let modulus = p.a().modulus();
+    let points = FixedBaseTable::new(p, 8);
+
+    for count in [2, 8, 32, 64, 128, 256, 512, 2048] {
+        let ks = scalars(2 * count, modulus);
+        let terms: Vec<(u64, ECPoint)> = (0..count)
+            .map(|i| (ks[2 * i], points.mul(ks[2 * i + 1])))
+            .collect();
+
+        let time = |f: &dyn Fn() -> ECPoint| {
+            let start = Instant::now();
+            let result = f();
+            (result, start.elapsed().as_secs_f64() * 1e3)
+        };
+        let (naive, naive_ms) = time(&|| {
+            let mut sum = ECPoint::infinity(p.a(), p.b());
+            for &(k, q) in terms.iter() {
+                sum += q * k;
+            }
+            sum
+        });
+        let (straus, straus_ms) = time(&|| ECPoint::straus(&terms));
+        let (pippenger, pippenger_ms) = time(&|| ECPoint::pippenger(&terms));
+        assert_eq!(straus, naive);
+        assert_eq!(pippenger, naive);
+
+        println!(
+            "  {:>4} terms: naive {:>8.2} ms, straus {:>8.2} ms, pippenger {:>8.2} ms",
+            count, naive_ms, straus_ms, pippenger_ms
+        );
+    }
+}
+
+fn main() {
+    let curves = [
+        ("p = 1021", point(1021, 1018), 20_000),
+        ("p = 2^61 - 1", point((1 << 61) - 1, 7), 2_000),
+        ("p = 2^63 - 25", point((1 << 63) - 25, 7), 2_000),
+    ];
+    let methods = [
+        ScalarMulMethod::DoubleAndAdd,
+        ScalarMulMethod::Wnaf(2),
+        ScalarMulMethod::Wnaf(3),
+        ScalarMulMethod::Wnaf(4),
+        ScalarMulMethod::Wnaf(5),
+        ScalarMulMethod::Wnaf(6),
+        ScalarMulMethod::MontgomeryLadder,
+    ];
+
+    for (name, p, count) in curves.iter().cloned() {
+        println!("{} ({} multiplications)", name, count);
+        let ks = scalars(count, p.a().modulus());
+        let expected: Vec<ECPoint> = ks.iter().map(|&k| p * k).collect();
+
+        for method in methods {
+            let start = Instant::now();
+            for (&k, &q) in ks.iter().zip(expected.iter()) {
+                assert_eq!(p.mul_with(k, method), q);
+            }
+            let elapsed = start.elapsed();
+            println!(
+                "  {:<18} {:>10.2} us/mul",
+                format!("{:?}", method),
+                elapsed.as_secs_f64() * 1e6 / count as f64
+            );
+        }
+
+        for window in [2, 4, 6, 8] {
+            let start = Instant::now();
+            let table = FixedBaseTable::new(p, window);
+            let build = start.elapsed();
+
+            let start = Instant::now();
+            for (&k, &q) in ks.iter().zip(expected.iter()) {
+                assert_eq!(table.mul(k), q);
+            }
+            let elapsed = start.elapsed();
+            println!(
+                "  {:<18} {:>10.2} us/mul ({} points, built in {:.2} ms)",
+                format!("FixedBase({})", window),
+                elapsed.as_secs_f64() * 1e6 / count as f64,
+                table.len(),
+                build.as_secs_f64() * 1e3
+            );
+        }
+    }
+
+    multi_scalar_mul(curves[2].1);
+}
@@ -0,0 +1,202 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg},
+};
+
+use crate::felt::{big_felt::BigFelt, big_uint::BigUint};
+
+use super::ec_errors::ECError;
+
+// Point on a short Weierstrass curve over a big prime field, see ECPoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BigECPoint {
+    x: BigFelt,
+    y: BigFelt,
+    a: BigFelt,
+    b: BigFelt,
+    infinity: bool,
+}
+
+impl BigECPoint {
+    pub fn new(x: BigFelt, y: BigFelt, a: BigFelt, b: BigFelt) -> Result<Self, ECError> {
+        let lhs = y * y;
+        let rhs = x * x * x + a * x + b;
+        if lhs != rhs {
+            return Err(ECError::BigPointNotOnCurve(
+                x.value().to_string(),
+                y.value().to_string(),
+                a.value().to_string(),
+                b.value().to_string(),
+            ));
+        }
+        Ok(BigECPoint {
+            x,
+            y,
+            a,
+            b,
+            infinity: false,
+        })
+    }
+
+    pub fn infinity(a: BigFelt, b: BigFelt) -> BigECPoint {
+        let zero = BigFelt::from_u64(0, a.modulus());
+        BigECPoint {
+            x: zero,
+            y: zero,
+            a,
+            b,
+            infinity: true,
+        }
+    }
+
+    pub fn x(&self) -> BigFelt {
+        self.x
+    }
+
+    pub fn y(&self) -> BigFelt {
+        self.y
+    }
+
+    pub fn a(&self) -> BigFelt {
+        self.a
+    }
+
+    pub fn b(&self) -> BigFelt {
+        self.b
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.infinity
+    }
+}
+
+impl Add for BigECPoint {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.a != other.a || self.b != other.b {
+            panic!("Points {}, {} are not on the same curve", self, other);
+        }
+
+        // P + 0 = P
+        if self.infinity {
+            return other;
+        }
+        if other.infinity {
+            return self;
+        }
+
+        // P + (-P) = 0
+        if self == -other {
+            return BigECPoint::infinity(self.a, self.b);
+        }
+
+        let s = if self == other {
+            let modulus = self.a.modulus();
+            let felt_3 = BigFelt::from_u64(3, modulus);
+            let felt_2 = BigFelt::from_u64(2, modulus);
+
+            (felt_3 * self.x * self.x + self.a) / (felt_2 * self.y)
+        } else {
+            (other.y - self.y) / (other.x - self.x)
+        };
+
+        let x = s * s - self.x - other.x;
+        let y = s * (self.x - x) - self.y;
+
+        BigECPoint {
+            x,
+            y,
+            a: self.a,
+            b: self.b,
+            infinity: false,
+        }
+    }
+}
+
+impl AddAssign for BigECPoint {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Neg for BigECPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.infinity {
+            return self;
+        }
+        BigECPoint { y: -self.y, ..self }
+    }
+}
+
+impl Mul<BigUint> for BigECPoint {
+    type Output = Self;
+
+    fn mul(self, other: BigUint) -> Self {
+        let mut result = BigECPoint::infinity(self.a, self.b);
+        for i in (0..other.bits()).rev() {
+            result += result;
+            if other.bit(i) {
+                result += self;
+            }
+        }
+        result
+    }
+}
+
+impl Mul<BigECPoint> for BigUint {
+    type Output = BigECPoint;
+
+    fn mul(self, other: BigECPoint) -> BigECPoint {
+        other * self
+    }
+}
+
+impl Display for BigECPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.infinity {
+            write!(f, "Infinity")
+        } else {
+            write!(f, "({}, {})", self.x.value(), self.y.value())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn felt(value: u64) -> BigFelt {
+        BigFelt::from_u64(value, BigUint::from_u64(1021))
+    }
+
+    #[test]
+    fn test_point_not_on_curve() {
+        assert!(BigECPoint::new(felt(1), felt(1), -felt(3), -felt(3)).is_err());
+    }
+
+    // Matches ECPoint on the curve y^2 = x^3 - 3x - 3 mod 1021 of prime order 1039
+    #[test]
+    fn test_multiplication() {
+        let g = BigECPoint::new(felt(379), felt(1011), -felt(3), -felt(3)).unwrap();
+        assert_eq!(
+            g * BigUint::from_u64(2),
+            BigECPoint::new(felt(57), felt(914), -felt(3), -felt(3)).unwrap()
+        );
+        assert_eq!(g + g, g * BigUint::from_u64(2));
+        assert!((g * BigUint::from_u64(1039)).is_infinity());
+        assert_eq!(g * BigUint::from_u64(1038), -g);
+    }
+
+    #[test]
+    fn test_display() {
+        let g = BigECPoint::new(felt(379), felt(1011), -felt(3), -felt(3)).unwrap();
+        assert_eq!(format!("{}", g), "(0x17b, 0x3f3)");
+        assert_eq!(
+            format!("{}", BigECPoint::infinity(-felt(3), -felt(3))),
+            "Infinity"
+        );
+    }
+}
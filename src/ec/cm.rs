@@ -0,0 +1,322 @@
+use std::{
+    f64::consts::PI,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::felt::felt::Felt;
+
+use super::ec_point::ECPoint;
+
+// Coefficients of Hilbert class polynomials are recovered by rounding
+// products of floating point j-invariants, which is only exact below this
+const MAX_COEFFICIENT: f64 = (1_u64 << 50) as f64;
+
+// Terms of the q-series for E4 and the discriminant, |q| <= e^(-pi sqrt(3))
+// for reduced forms so this is far below f64 precision
+const Q_SERIES_TERMS: i32 = 40;
+
+// Primitive reduced forms ax^2 + bxy + cy^2 of discriminant d = b^2 - 4ac < 0,
+// i.e. |b| <= a <= c with b >= 0 when |b| = a or a = c. There is one for
+// every class in the class group, None if d is not a discriminant.
+pub fn reduced_forms(d: i64) -> Option<Vec<(i64, i64, i64)>> {
+    if d >= 0 || !(d.rem_euclid(4) == 0 || d.rem_euclid(4) == 1) {
+        return None;
+    }
+
+    let mut forms = Vec::new();
+    let mut a = 1;
+    // a <= sqrt(|d| / 3) for reduced forms
+    while 3 * a * a <= -d {
+        for b in -a + 1..=a {
+            let numerator = b * b - d;
+            if numerator % (4 * a) != 0 {
+                continue;
+            }
+            let c = numerator / (4 * a);
+            if c < a || (b < 0 && a == c) || gcd(gcd(a, b.abs()), c) != 1 {
+                continue;
+            }
+            forms.push((a, b, c));
+        }
+        a += 1;
+    }
+    Some(forms)
+}
+
+pub fn class_number(d: i64) -> Option<usize> {
+    reduced_forms(d).map(|forms| forms.len())
+}
+
+// H_d(x) = prod (x - j((-b + sqrt(d)) / 2a)) over the reduced forms, as
+// integer coefficients from the constant term up. The roots are evaluated in
+// f64, so this is None once a coefficient gets too large to round exactly
+// (e.g. d = -163, where j = -640320^3) or if d is not a discriminant.
+pub fn hilbert_class_polynomial(d: i64) -> Option<Vec<i128>> {
+    let mut coefficients = vec![Complex::new(1.0, 0.0)];
+    for (a, b, _) in reduced_forms(d)? {
+        let tau = Complex::new(-b as f64, (-d as f64).sqrt()).scale(0.5 / a as f64);
+        let root = j_invariant(tau);
+
+        // Multiply by (x - root)
+        let mut product = vec![Complex::new(0.0, 0.0); coefficients.len() + 1];
+        for (i, &c) in coefficients.iter().enumerate() {
+            product[i] = product[i] - c * root;
+            product[i + 1] = product[i + 1] + c;
+        }
+        coefficients = product;
+    }
+
+    coefficients
+        .into_iter()
+        .map(|c| (c.re.abs() < MAX_COEFFICIENT).then(|| c.re.round() as i128))
+        .collect()
+}
+
+// Possible orders p + 1 - t of curves over F_p with CM by discriminant d, for
+// the traces t with 4p = t^2 - dv^2. Empty if p does not split as needed.
+// Searches t in O(sqrt(p)).
+pub fn cm_orders(p: u64, d: i64) -> Vec<u64> {
+    let bound = 2 * (p as f64).sqrt() as i128 + 1;
+    let (p, d) = (p as i128, d as i128);
+    let mut orders = Vec::new();
+    for t in -bound..=bound {
+        let rest = 4 * p - t * t;
+        if rest <= 0 || rest % -d != 0 {
+            continue;
+        }
+        let square = rest / -d;
+        let v = (square as f64).sqrt().round() as i128;
+        if v * v == square {
+            orders.push((p + 1 - t) as u64);
+        }
+    }
+    orders.sort_unstable();
+    orders
+}
+
+// A curve y^2 = x^3 + ax + b over F_p with exactly order points built by
+// the CM method: the roots of H_d mod p are j-invariants of curves with CM
+// by d, and the curve or one of its twists has the order. None if order is
+// not one of cm_orders(p, d) or H_d could not be computed. Roots and orders
+// are checked by brute force in O(p), so this is for small fields.
+pub fn cm_curve(p: u64, d: i64, order: u64) -> Option<(Felt, Felt)> {
+    if !cm_orders(p, d).contains(&order) {
+        return None;
+    }
+    let polynomial: Vec<Felt> = hilbert_class_polynomial(d)?
+        .into_iter()
+        .map(|c| Felt::new(c.rem_euclid(p as i128) as u64, p))
+        .collect();
+
+    let zero = Felt::new(0, p);
+    let has_order = |a: Felt, b: Felt| {
+        let singular = (Felt::new(4, p) * a.pow(3) + Felt::new(27, p) * b.pow(2)).value() == 0;
+        !singular && ECPoint::count_points(a, b) == order
+    };
+
+    for j in (0..p).map(|j| Felt::new(j, p)) {
+        let value = polynomial.iter().rev().fold(zero, |acc, &c| acc * j + c);
+        if value != zero {
+            continue;
+        }
+
+        // j = 0 and j = 1728 have 6 and 4 twists, try multiples of b or a
+        let found = if j == zero {
+            (1..p)
+                .map(|b| (zero, Felt::new(b, p)))
+                .find(|&(a, b)| has_order(a, b))
+        } else if j == Felt::new(1728, p) {
+            (1..p)
+                .map(|a| (Felt::new(a, p), zero))
+                .find(|&(a, b)| has_order(a, b))
+        } else {
+            // j = 1728k / (k + 1) for y^2 = x^3 + 3kx + 2k
+            let k = j / (Felt::new(1728, p) - j);
+            let (a, b) = (Felt::new(3, p) * k, Felt::new(2, p) * k);
+            let (twist_a, twist_b) = ECPoint::quadratic_twist(a, b);
+            [(a, b), (twist_a, twist_b)]
+                .into_iter()
+                .find(|&(a, b)| has_order(a, b))
+        };
+        if found.is_some() {
+            return found;
+        }
+    }
+
+    None
+}
+
+// Klein's j = E4^3 / Delta with q = e^(2 pi i tau),
+// E4 = 1 + 240 sum n^3 q^n / (1 - q^n) and Delta = q prod (1 - q^n)^24
+fn j_invariant(tau: Complex) -> Complex {
+    let q = Complex::from_polar((-2.0 * PI * tau.im).exp(), 2.0 * PI * tau.re);
+    let one = Complex::new(1.0, 0.0);
+
+    let mut e4 = one;
+    let mut product = one;
+    let mut q_n = one;
+    for n in 1..=Q_SERIES_TERMS {
+        q_n = q_n * q;
+        let term = one - q_n;
+        e4 = e4 + (q_n / term).scale(240.0 * (n as f64).powi(3));
+        product = product * term;
+    }
+
+    let delta = q * product.powi(24);
+    e4.powi(3) / delta
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+
+    fn from_polar(r: f64, theta: f64) -> Self {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+
+    fn scale(self, s: f64) -> Self {
+        Complex::new(self.re * s, self.im * s)
+    }
+
+    fn powi(self, n: u32) -> Self {
+        (0..n).fold(Complex::new(1.0, 0.0), |acc, _| acc * self)
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let norm = other.re * other.re + other.im * other.im;
+        Complex::new(
+            (self.re * other.re + self.im * other.im) / norm,
+            (self.im * other.re - self.re * other.im) / norm,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_class_numbers() {
+        for d in [-3, -4, -7, -8, -11, -19, -43, -67, -163] {
+            assert_eq!(class_number(d), Some(1), "{}", d);
+        }
+        assert_eq!(class_number(-15), Some(2));
+        assert_eq!(class_number(-20), Some(2));
+        assert_eq!(class_number(-23), Some(3));
+        assert_eq!(
+            reduced_forms(-23).unwrap(),
+            vec![(1, 1, 6), (2, -1, 3), (2, 1, 3)]
+        );
+        assert_eq!(class_number(-5), None);
+        assert_eq!(class_number(4), None);
+    }
+
+    #[test]
+    fn test_hilbert_class_polynomials() {
+        assert_eq!(hilbert_class_polynomial(-3), Some(vec![0, 1]));
+        assert_eq!(hilbert_class_polynomial(-4), Some(vec![-1728, 1]));
+        assert_eq!(hilbert_class_polynomial(-7), Some(vec![3375, 1]));
+        assert_eq!(hilbert_class_polynomial(-8), Some(vec![-8000, 1]));
+        assert_eq!(hilbert_class_polynomial(-67), Some(vec![147197952000, 1]));
+        assert_eq!(
+            hilbert_class_polynomial(-15),
+            Some(vec![-121287375, 191025, 1])
+        );
+        assert_eq!(
+            hilbert_class_polynomial(-23),
+            Some(vec![12771880859375, -5151296875, 3491750, 1])
+        );
+        // j = -640320^3 is beyond f64 precision
+        assert_eq!(hilbert_class_polynomial(-163), None);
+    }
+
+    #[test]
+    fn test_cm_orders() {
+        assert_eq!(cm_orders(1021, -15), vec![960, 1084]);
+        assert_eq!(cm_orders(1021, -4), vec![962, 1000, 1044, 1082]);
+        assert_eq!(cm_orders(1021, -3).len(), 6);
+        assert!(cm_orders(1021, -7).is_empty());
+    }
+
+    #[test]
+    fn test_cm_curves() {
+        for (p, d) in [(1021, -15), (1021, -11), (10007, -43), (10007, -7)] {
+            for order in cm_orders(p, d) {
+                let (a, b) = cm_curve(p, d, order).unwrap();
+                assert_eq!(ECPoint::count_points(a, b), order, "{} {} {}", p, d, order);
+            }
+        }
+        assert_eq!(cm_curve(1021, -15, 1039), None);
+        assert_eq!(cm_curve(1021, -7, 1039), None);
+    }
+
+    #[test]
+    fn test_cm_curves_with_extra_twists() {
+        for d in [-3, -4] {
+            for order in cm_orders(1021, d) {
+                let (a, b) = cm_curve(1021, d, order).unwrap();
+                assert_eq!(ECPoint::count_points(a, b), order);
+            }
+        }
+    }
+
+    // A prime order curve to test the DLP solvers on
+    #[test]
+    fn test_prime_order_curve_for_dlp() {
+        let (a, b) = cm_curve(10079, -43, 10247).unwrap();
+        let x = (0..10079)
+            .map(|x| Felt::new(x, 10079))
+            .find(|&x| (x.pow(3) + a * x + b).sqrt().is_some())
+            .unwrap();
+        let y = (x.pow(3) + a * x + b).sqrt().unwrap();
+        let g = ECPoint::new(x, y, a, b).unwrap();
+        assert_eq!(g.order_dividing(10247), 10247);
+        assert_eq!(g.solve_dlp_baby_step_giant_step(g * 4242), Some(4242));
+    }
+}
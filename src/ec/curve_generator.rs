@@ -0,0 +1,291 @@
+use rand::Rng;
+
+use crate::felt::{
+    felt::Felt,
+    primes::{divisors, is_prime, largest_prime_factor, pow_mod},
+};
+
+use super::{ec_point::ECPoint, subgroup::PrimeSubgroup};
+
+// Why a curve was rejected by CurveGenerator::check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    Singular,
+    // #E / q for the largest prime q dividing #E
+    LargeCofactor(u64),
+    // The subgroup has order p, the discrete log is solvable in linear time
+    // (Smart's attack)
+    Anomalous,
+    // The MOV/Frey-Ruck attack moves the discrete log to F_p^k
+    LowEmbeddingDegree(u64),
+    // Cofactor of the twist, invalid curve attacks on x-only arithmetic land
+    // there
+    WeakTwist(u64),
+}
+
+// Samples random y^2 = x^3 + ax + b over F_p and keeps those with a large
+// prime order subgroup that resist the classic attacks. Points are counted
+// in O(p), so this is for small fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurveGenerator {
+    modulus: u64,
+    max_cofactor: u64,
+    min_embedding_degree: u64,
+    max_twist_cofactor: u64,
+    max_attempts: u64,
+}
+
+// A curve accepted by CurveGenerator with a generator of its prime order
+// subgroup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeneratedCurve {
+    a: Felt,
+    b: Felt,
+    subgroup: PrimeSubgroup,
+    embedding_degree: u64,
+    twist_order: u64,
+}
+
+impl CurveGenerator {
+    // Defaults to prime order curves with embedding degree at least 20 and a
+    // twist cofactor of at most 8, giving up after 10000 random curves
+    pub fn new(modulus: u64) -> Self {
+        assert!(
+            modulus > 3 && is_prime(modulus),
+            "Modulus must be a prime greater than 3"
+        );
+        CurveGenerator {
+            modulus,
+            max_cofactor: 1,
+            min_embedding_degree: 20,
+            max_twist_cofactor: 8,
+            max_attempts: 10_000,
+        }
+    }
+
+    pub fn with_max_cofactor(mut self, cofactor: u64) -> Self {
+        self.max_cofactor = cofactor;
+        self
+    }
+
+    pub fn with_min_embedding_degree(mut self, degree: u64) -> Self {
+        self.min_embedding_degree = degree;
+        self
+    }
+
+    pub fn with_max_twist_cofactor(mut self, cofactor: u64) -> Self {
+        self.max_twist_cofactor = cofactor;
+        self
+    }
+
+    pub fn with_max_attempts(mut self, attempts: u64) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    // Tries random curves until one passes check. None if none of them did,
+    // or right away if the embedding degree bound cannot be met: k divides
+    // q - 1 and q <= #E <= p + 1 + 2 sqrt(p) by Hasse's theorem.
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<GeneratedCurve> {
+        let hasse_bound = self.modulus + 1 + 2 * (self.modulus.isqrt() + 1);
+        if self.min_embedding_degree >= hasse_bound {
+            return None;
+        }
+        for _ in 0..self.max_attempts {
+            let a = Felt::new(rng.gen_range(0..self.modulus), self.modulus);
+            let b = Felt::new(rng.gen_range(0..self.modulus), self.modulus);
+            if self.check(a, b).is_ok() {
+                let subgroup = PrimeSubgroup::find(a, b, rng).unwrap();
+                return Some(GeneratedCurve {
+                    a,
+                    b,
+                    subgroup,
+                    embedding_degree: embedding_degree(self.modulus, subgroup.order()).unwrap(),
+                    twist_order: 2 * self.modulus + 2 - subgroup.order() * subgroup.cofactor(),
+                });
+            }
+        }
+        None
+    }
+
+    // Checks the curve y^2 = x^3 + ax + b against this generator's bounds
+    pub fn check(&self, a: Felt, b: Felt) -> Result<(), Rejection> {
+        let four = Felt::new(4, self.modulus);
+        let twenty_seven = Felt::new(27, self.modulus);
+        if (four * a.pow(3) + twenty_seven * b.pow(2)).value() == 0 {
+            return Err(Rejection::Singular);
+        }
+
+        let count = ECPoint::count_points(a, b);
+        let order = largest_prime_factor(count);
+        let cofactor = count / order;
+        if cofactor > self.max_cofactor {
+            return Err(Rejection::LargeCofactor(cofactor));
+        }
+        if order == self.modulus {
+            return Err(Rejection::Anomalous);
+        }
+
+        let degree = embedding_degree(self.modulus, order).unwrap();
+        if degree < self.min_embedding_degree {
+            return Err(Rejection::LowEmbeddingDegree(degree));
+        }
+
+        let twist_order = ECPoint::twist_order(a, b);
+        let twist_cofactor = twist_order / largest_prime_factor(twist_order);
+        if twist_cofactor > self.max_twist_cofactor {
+            return Err(Rejection::WeakTwist(twist_cofactor));
+        }
+
+        Ok(())
+    }
+}
+
+impl GeneratedCurve {
+    pub fn a(&self) -> Felt {
+        self.a
+    }
+
+    pub fn b(&self) -> Felt {
+        self.b
+    }
+
+    // Number of points #E = h * q
+    pub fn order(&self) -> u64 {
+        self.subgroup.order() * self.subgroup.cofactor()
+    }
+
+    pub fn subgroup(&self) -> PrimeSubgroup {
+        self.subgroup
+    }
+
+    pub fn generator(&self) -> ECPoint {
+        self.subgroup.generator()
+    }
+
+    pub fn embedding_degree(&self) -> u64 {
+        self.embedding_degree
+    }
+
+    pub fn twist_order(&self) -> u64 {
+        self.twist_order
+    }
+}
+
+// Smallest k with q | p^k - 1, the order of p in (Z/q)^*. None if q divides
+// p, where no such k exists.
+pub fn embedding_degree(p: u64, q: u64) -> Option<u64> {
+    if p.is_multiple_of(q) {
+        return None;
+    }
+    divisors(q - 1)
+        .into_iter()
+        .find(|&k| pow_mod(p, k, q) == 1 % q)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_embedding_degree() {
+        // y^2 = x^3 + x mod 43 is supersingular with #E = 44, so k = 2
+        assert_eq!(embedding_degree(43, 11), Some(2));
+        assert_eq!(embedding_degree(1021, 1039), Some(346));
+        assert_eq!(embedding_degree(43, 13), Some(6));
+        assert_eq!(embedding_degree(43, 43), None);
+    }
+
+    #[test]
+    fn test_generated_curves_pass_check() {
+        let mut rng = StdRng::seed_from_u64(1021);
+        let generator = CurveGenerator::new(1021);
+        for _ in 0..3 {
+            let curve = generator.generate(&mut rng).unwrap();
+            assert_eq!(generator.check(curve.a(), curve.b()), Ok(()));
+            assert_eq!(curve.order(), ECPoint::count_points(curve.a(), curve.b()));
+            assert!(is_prime(curve.order()));
+            assert_eq!(curve.generator().order(), curve.order());
+            assert!(curve.embedding_degree() >= 20);
+            assert_eq!(curve.order() + curve.twist_order(), 2 * 1021 + 2);
+        }
+    }
+
+    #[test]
+    fn test_cofactor() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let generator = CurveGenerator::new(10007).with_max_cofactor(4);
+        let curve = generator.generate(&mut rng).unwrap();
+        let subgroup = curve.subgroup();
+        assert!(subgroup.cofactor() <= 4);
+        assert!(is_prime(subgroup.order()));
+        assert_eq!(curve.generator().order(), subgroup.order());
+    }
+
+    #[test]
+    fn test_rejections() {
+        let generator = CurveGenerator::new(43);
+        let felt = |v| Felt::new(v, 43);
+        assert_eq!(generator.check(felt(0), felt(0)), Err(Rejection::Singular));
+        // The main.rs curve has #E = 39 = 3 * 13
+        assert_eq!(
+            generator.check(felt(0), felt(6)),
+            Err(Rejection::LargeCofactor(3))
+        );
+        // 43 has order 6 mod 13
+        assert_eq!(
+            generator.with_max_cofactor(3).check(felt(0), felt(6)),
+            Err(Rejection::LowEmbeddingDegree(6))
+        );
+        // #E = 43
+        assert_eq!(
+            generator.check(felt(1), felt(14)),
+            Err(Rejection::Anomalous)
+        );
+        // #E = 37 and 43^4 = 1 (mod 37)
+        assert_eq!(
+            generator.check(felt(1), felt(5)),
+            Err(Rejection::LowEmbeddingDegree(4))
+        );
+        assert_eq!(
+            generator
+                .with_min_embedding_degree(4)
+                .check(felt(1), felt(5)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_weak_twist() {
+        // #E = 83 is prime but the twist has order 121 = 11^2
+        let generator = CurveGenerator::new(101);
+        let (a, b) = (Felt::new(4, 101), Felt::new(23, 101));
+        assert_eq!(generator.check(a, b), Err(Rejection::WeakTwist(11)));
+        assert_eq!(generator.with_max_twist_cofactor(11).check(a, b), Ok(()));
+    }
+
+    // Over F_5 the prime q dividing #E is at most 7, so k <= 6
+    #[test]
+    fn test_impossible_constraints() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let generator = CurveGenerator::new(5).with_max_cofactor(10);
+        assert_eq!(generator.generate(&mut rng), None);
+        let generator = generator
+            .with_min_embedding_degree(7)
+            .with_max_attempts(100);
+        assert_eq!(generator.generate(&mut rng), None);
+        let generator = generator.with_min_embedding_degree(1);
+        assert!(generator.generate(&mut rng).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus must be a prime greater than 3")]
+    fn test_composite_modulus_should_panic() {
+        CurveGenerator::new(1023);
+    }
+}
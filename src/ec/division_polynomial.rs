@@ -0,0 +1,229 @@
+use rand::Rng;
+
+use crate::felt::{felt::Felt, polynomial::Polynomial};
+
+use super::ec_point::ECPoint;
+
+// The n-th division polynomial of y^2 = x^3 + ax + b as a polynomial in x.
+// psi_n vanishes exactly at the n-torsion points; for even n it has a factor
+// 2y, so this returns f_n with psi_n = f_n for odd n and psi_n = 2y f_n for
+// even n.
+pub fn division_polynomial(a: Felt, b: Felt, n: u64) -> Polynomial {
+    division_polynomials(a, b, n).pop().unwrap()
+}
+
+// f_0, ..., f_n from the recurrences
+// psi_2m+1 = psi_m+2 psi_m^3 - psi_m-1 psi_m+1^3 and
+// psi_2m = psi_m (psi_m+2 psi_m-1^2 - psi_m-2 psi_m+1^2) / 2y,
+// where (2y)^2 = F = 4(x^3 + ax + b) takes the place of the even factors
+pub fn division_polynomials(a: Felt, b: Felt, n: u64) -> Vec<Polynomial> {
+    let modulus = a.modulus();
+    let felt = |value| Felt::new(value, modulus);
+    let zero = felt(0);
+    let f_squared = {
+        let f = Polynomial::new(vec![b, a, zero, felt(1)], modulus).scale(felt(4));
+        &f * &f
+    };
+
+    let mut polynomials = vec![
+        Polynomial::zero(modulus),
+        Polynomial::one(modulus),
+        Polynomial::one(modulus),
+        // 3x^4 + 6ax^2 + 12bx - a^2
+        Polynomial::new(
+            vec![-a * a, felt(12) * b, felt(6) * a, zero, felt(3)],
+            modulus,
+        ),
+        // 2(x^6 + 5ax^4 + 20bx^3 - 5a^2x^2 - 4abx - 8b^2 - a^3)
+        Polynomial::new(
+            vec![
+                -felt(8) * b * b - a.pow(3),
+                -felt(4) * a * b,
+                -felt(5) * a * a,
+                felt(20) * b,
+                felt(5) * a,
+                zero,
+                felt(1),
+            ],
+            modulus,
+        )
+        .scale(felt(2)),
+    ];
+
+    for k in 5..=n as usize {
+        let m = k / 2;
+        let f = |i: usize| &polynomials[i];
+        let next = if k % 2 == 1 {
+            let first = f(m + 2) * &(&(f(m) * f(m)) * f(m));
+            let second = f(m - 1) * &(&(f(m + 1) * f(m + 1)) * f(m + 1));
+            if m % 2 == 0 {
+                &(&f_squared * &first) - &second
+            } else {
+                &first - &(&f_squared * &second)
+            }
+        } else {
+            let first = f(m + 2) * &(f(m - 1) * f(m - 1));
+            let second = f(m - 2) * &(f(m + 1) * f(m + 1));
+            f(m) * &(&first - &second)
+        };
+        polynomials.push(next);
+    }
+
+    polynomials.truncate(n as usize + 1);
+    polynomials
+}
+
+// E[n](F_p), the points with nP = 0: the point at infinity, the points whose
+// x is a root of f_n and for even n the points of order 2. Roots are found
+// with Cantor-Zassenhaus, the randomness only affects the running time.
+pub fn torsion_points<R: Rng + ?Sized>(a: Felt, b: Felt, n: u64, rng: &mut R) -> Vec<ECPoint> {
+    assert!(n > 0, "n must be positive");
+    let modulus = a.modulus();
+    let discriminant = Felt::new(4, modulus) * a.pow(3) + Felt::new(27, modulus) * b.pow(2);
+    assert!(
+        discriminant.value() != 0,
+        "The curve y^2 = x^3 + {}x + {} is singular",
+        a.value(),
+        b.value()
+    );
+    let mut xs = division_polynomial(a, b, n).roots(rng);
+    if n.is_multiple_of(2) {
+        let cubic = Polynomial::new(
+            vec![b, a, Felt::new(0, modulus), Felt::new(1, modulus)],
+            modulus,
+        );
+        xs.extend(cubic.roots(rng));
+        xs.sort_by_key(|x| x.value());
+        xs.dedup();
+    }
+
+    let mut points = vec![ECPoint::infinity(a, b)];
+    for x in xs {
+        if let Some(y) = (x.pow(3) + a * x + b).sqrt() {
+            points.push(ECPoint::new(x, y, a, b).unwrap());
+            if y.value() != 0 {
+                points.push(ECPoint::new(x, -y, a, b).unwrap());
+            }
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ec::group_structure::GroupStructure;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_small_division_polynomials() {
+        let (a, b) = (Felt::new(2, 101), Felt::new(3, 101));
+        // 3x^4 + 12x^2 + 36x - 4
+        assert_eq!(
+            division_polynomial(a, b, 3),
+            Polynomial::from_u64(&[97, 36, 12, 0, 3], 101)
+        );
+        assert!(division_polynomial(a, b, 0).is_zero());
+        assert!(division_polynomial(a, b, 2).is_one());
+    }
+
+    // f_n has degree (n^2 - 1) / 2 and leading coefficient n for odd n,
+    // (n^2 - 4) / 2 and n / 2 for even n
+    #[test]
+    fn test_degrees() {
+        let (a, b) = (Felt::new(2, 101), Felt::new(3, 101));
+        for (n, f) in division_polynomials(a, b, 12)
+            .into_iter()
+            .enumerate()
+            .skip(1)
+        {
+            let n = n as u64;
+            let (degree, lead) = if n % 2 == 1 {
+                ((n * n - 1) / 2, n)
+            } else {
+                ((n * n - 4) / 2, n / 2)
+            };
+            assert_eq!(f.degree(), Some(degree as usize), "{}", n);
+            assert_eq!(f.leading_coefficient(), Some(Felt::new(lead, 101)));
+        }
+    }
+
+    // x(nP) = x - psi_n-1 psi_n+1 / psi_n^2
+    #[test]
+    fn test_multiplication_formula() {
+        let (a, b) = (-Felt::new(3, 1021), -Felt::new(3, 1021));
+        let p = ECPoint::new(Felt::new(379, 1021), Felt::new(1011, 1021), a, b).unwrap();
+        let f = division_polynomials(a, b, 8);
+        let x = p.x();
+        let four_y_squared = Felt::new(4, 1021) * (x.pow(3) + a * x + b);
+        for n in 2..=7 {
+            let (previous, current, next) =
+                (f[n - 1].evaluate(x), f[n].evaluate(x), f[n + 1].evaluate(x));
+            let expected = if n % 2 == 1 {
+                x - four_y_squared * previous * next / current.pow(2)
+            } else {
+                x - previous * next / (four_y_squared * current.pow(2))
+            };
+            assert_eq!((p * n as u64).x(), expected, "{}", n);
+        }
+    }
+
+    // Over 119 * 2^23 + 1 the larger products are computed with the NTT
+    #[test]
+    fn test_multiplication_formula_ntt_friendly_prime() {
+        let modulus = 998244353;
+        let (a, b) = (Felt::new(2, modulus), Felt::new(3, modulus));
+        let (x, y) = (1..)
+            .map(|x| Felt::new(x, modulus))
+            .find_map(|x| (x.pow(3) + a * x + b).sqrt().map(|y| (x, y)))
+            .unwrap();
+        let p = ECPoint::new(x, y, a, b).unwrap();
+        let f = division_polynomials(a, b, 24);
+        assert_eq!(f[23].degree(), Some(264));
+        let four_y_squared = Felt::new(4, modulus) * y.pow(2);
+        for n in 20..=23 {
+            let (previous, current, next) =
+                (f[n - 1].evaluate(x), f[n].evaluate(x), f[n + 1].evaluate(x));
+            let expected = if n % 2 == 1 {
+                x - four_y_squared * previous * next / current.pow(2)
+            } else {
+                x - previous * next / (four_y_squared * current.pow(2))
+            };
+            assert_eq!((p * n as u64).x(), expected, "{}", n);
+        }
+    }
+
+    // |E[n]| = gcd(n, n1) gcd(n, n2) for E = Z/n1 x Z/n2
+    #[test]
+    fn test_torsion_points() {
+        let curves = [
+            (-Felt::new(1, 61), Felt::new(0, 61)),
+            (Felt::new(0, 31), Felt::new(1, 31)),
+            (Felt::new(0, 43), Felt::new(6, 43)),
+        ];
+        let mut rng = StdRng::seed_from_u64(48);
+        for (a, b) in curves {
+            let (n1, n2) = GroupStructure::compute(a, b).invariants();
+            for n in 1..=13 {
+                let points = torsion_points(a, b, n, &mut rng);
+                let gcd = |m: u64| {
+                    (1..=n)
+                        .filter(|&d| n.is_multiple_of(d) && m.is_multiple_of(d))
+                        .max()
+                        .unwrap()
+                };
+                let expected = gcd(n1) * gcd(n2);
+                assert_eq!(points.len() as u64, expected, "{} {} {}", a, b, n);
+                assert!(points.iter().all(|&p| (p * n).is_infinity()));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The curve y^2 = x^3 + 58x + 2 is singular")]
+    fn test_torsion_points_on_singular_curve_should_panic() {
+        // 4(-3)^3 + 27 * 2^2 = 0, y^2 = (x - 1)^2 (x + 2)
+        let (a, b) = (-Felt::new(3, 61), Felt::new(2, 61));
+        torsion_points(a, b, 2, &mut StdRng::seed_from_u64(48));
+    }
+}
@@ -4,4 +4,38 @@ use thiserror::Error;
 pub enum ECError {
     #[error("Point ({0}, {1}) is not on the curve y^2 = x^3 + {2}x + {3}")]
     PointNotOnCurve(u64, u64, u64, u64),
+    #[error("Point ({0}, {1}) is not on the curve y^2 = x^3 + {2}x + {3}")]
+    BigPointNotOnCurve(String, String, String, String),
+    #[error("Point ({0}, {1}) is not on the curve y^2 = x^3 + {2}x + {3}")]
+    Fp2PointNotOnCurve(String, String, u64, u64),
+    #[error("Point ({0}, {1}) is not on the curve y^2 = x^3 + {2}")]
+    TwistPointNotOnCurve(String, String, String),
+    #[error("Point ({0}, {1}) is not on the curve {3}y^2 = x^3 + {2}x^2 + x")]
+    PointNotOnMontgomeryCurve(u64, u64, u64, u64),
+    #[error("Point is on the curve {0}, not on the curve {1}")]
+    CurveMismatch(String, String),
+    #[error("The curve {1}y^2 = x^3 + {0}x^2 + x is singular")]
+    SingularMontgomeryCurve(u64, u64),
+    #[error("The curve y^2 = x^3 + {0}x + {1} has no Montgomery form")]
+    NoMontgomeryForm(u64, u64),
+    #[error("Point ({0}, {1}) is not on the curve {2}x^2 + y^2 = 1 + {3}x^2y^2")]
+    PointNotOnEdwardsCurve(u64, u64, u64, u64),
+    #[error("The curve {0}x^2 + y^2 = 1 + {1}x^2y^2 is singular")]
+    SingularEdwardsCurve(u64, u64),
+    #[error("Point ({0}, {1}) maps to a point at infinity on the Edwards curve")]
+    NoEdwardsImage(u64, u64),
+    #[error("Point ({0}, {1}) is not on the curve y^2 + {2}xy + {4}y = x^3 + {3}x^2 + {5}x + {6}")]
+    PointNotOnWeierstrassCurve(u64, u64, u64, u64, u64, u64, u64),
+    #[error("The curve y^2 + {0}xy + {2}y = x^3 + {1}x^2 + {3}x + {4} is singular")]
+    SingularWeierstrassCurve(u64, u64, u64, u64, u64),
+    #[error("Curves over fields of characteristic {0} have no short Weierstrass form")]
+    NoShortWeierstrassForm(u64),
+    #[error("Curve {0} is not a short Weierstrass curve over a 64-bit field")]
+    UnsupportedNamedCurve(String),
+    #[error("Modulus {0} is not an odd prime")]
+    UnsupportedModulus(u64),
+    #[error("Invalid SEC1 point encoding: {0}")]
+    InvalidSec1Encoding(String),
+    #[error("Invalid point at position {0}: {1}")]
+    Parse(usize, String),
 }
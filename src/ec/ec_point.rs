@@ -2,11 +2,31 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::{Display, Formatter},
     ops::{Add, AddAssign, Mul, Neg},
+    str::FromStr,
 };
 
-use crate::felt::felt::Felt;
+use crate::felt::{
+    felt::Felt,
+    parser::{signed_felt, ParseResult, Parser},
+    primes::{is_prime, prime_factors},
+};
+
+use super::{
+    ec_errors::ECError,
+    named_curves::named_curve,
+    weierstrass_curve::{VariableChange, WeierstrassCurve},
+};
 
-use super::ec_errors::ECError;
+// Start offset and signed coordinates of a parsed point, None for infinity
+type ParsedPoint = (usize, Option<[(bool, u64); 2]>);
+
+// SEC1 point encodings, the prefix byte is 0x02/0x03, 0x04 and 0x06/0x07
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sec1Format {
+    Compressed,
+    Uncompressed,
+    Hybrid,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ECPoint {
@@ -56,6 +76,92 @@ impl ECPoint {
         }
     }
 
+    pub fn x(&self) -> Felt {
+        self.x
+    }
+
+    pub fn y(&self) -> Felt {
+        self.y
+    }
+
+    pub fn a(&self) -> Felt {
+        self.a
+    }
+
+    pub fn b(&self) -> Felt {
+        self.b
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.infinity
+    }
+
+    // j = 1728 * 4a^3 / (4a^3 + 27b^2), panics if the curve is singular
+    pub fn j_invariant(&self) -> Felt {
+        let modulus = self.a.modulus();
+        let felt_4a3 = Felt::new(4, modulus) * self.a.pow(3);
+        let felt_27b2 = Felt::new(27, modulus) * self.b.pow(2);
+
+        Felt::new(1728, modulus) * felt_4a3 / (felt_4a3 + felt_27b2)
+    }
+
+    // Change of variables (u, r, s, t) from y^2 = x^3 + a1x + b1 onto
+    // y^2 = x^3 + a2x + b2, or None if the curves are not isomorphic over F_p
+    pub fn isomorphism(a1: Felt, b1: Felt, a2: Felt, b2: Felt) -> Option<VariableChange> {
+        let e1 = WeierstrassCurve::from_short(a1, b1).ok()?;
+        let e2 = WeierstrassCurve::from_short(a2, b2).ok()?;
+        e1.isomorphism(&e2).ok()?
+    }
+
+    pub fn is_isomorphic(a1: Felt, b1: Felt, a2: Felt, b2: Felt) -> bool {
+        ECPoint::isomorphism(a1, b1, a2, b2).is_some()
+    }
+
+    // Image of the point under an isomorphism onto y^2 = x^3 + ax + b
+    pub fn map(&self, change: VariableChange, a: Felt, b: Felt) -> Result<ECPoint, ECError> {
+        if self.infinity {
+            return Ok(ECPoint::infinity(a, b));
+        }
+        let (x, y) = change.map_point(self.x, self.y);
+        ECPoint::new(x, y, a, b)
+    }
+
+    // Number of points, including infinity, using
+    // #E = p + 1 + sum over x of legendre(x^3 + ax + b)
+    pub fn count_points(a: Felt, b: Felt) -> u64 {
+        let modulus = a.modulus();
+        let one = Felt::new(1, modulus);
+        let mut count = 1;
+
+        for x in 0..modulus {
+            let x = Felt::new(x, modulus);
+            let rhs = x.pow(3) + a * x + b;
+            if rhs.value() == 0 {
+                count += 1;
+            } else if rhs.pow((modulus - 1) / 2) == one {
+                count += 2;
+            }
+        }
+
+        count
+    }
+
+    // Quadratic twist y^2 = x^3 + ad^2x + bd^3 by the smallest non-square d
+    pub fn quadratic_twist(a: Felt, b: Felt) -> (Felt, Felt) {
+        let modulus = a.modulus();
+        let d = Felt::smallest_nonresidue(modulus)
+            .unwrap_or_else(|| panic!("Every element of F_{} is a square", modulus));
+        (a * d.pow(2), b * d.pow(3))
+    }
+
+    // #E + #E' = 2p + 2
+    pub fn twist_order(a: Felt, b: Felt) -> u64 {
+        2 * a.modulus() + 2 - ECPoint::count_points(a, b)
+    }
+
+    // Adds the point to itself until reaching infinity, O(ord P) additions.
+    // Callers that know #E or a subgroup order should use order_dividing,
+    // which avoids walking the whole subgroup.
     pub fn order(&self) -> u64 {
         let mut gi = *self;
         let mut order = 1;
@@ -67,6 +173,36 @@ impl ECPoint {
         order
     }
 
+    // Order of the point given any multiple of it, e.g. #E or the subgroup
+    // order, which must be nonzero
+    pub fn order_dividing(&self, multiple: u64) -> u64 {
+        assert!(multiple != 0, "The multiple of the order must be nonzero");
+        self.order_from_factors(&prime_factors(multiple))
+    }
+
+    // Order given the factorization of a multiple of it that fits in a u64:
+    // start from the multiple and divide out each prime while the point is
+    // still killed. That takes O(sum of exponents) scalar multiplications.
+    pub fn order_from_factors(&self, factors: &[(u64, u32)]) -> u64 {
+        let mut order = factors
+            .iter()
+            .try_fold(1_u64, |acc, &(p, e)| acc.checked_mul(p.checked_pow(e)?))
+            .expect("The factorization must multiply to a u64");
+        if order == 0 || !(*self * order).is_infinity() {
+            panic!("The factorization is not of a multiple of the order");
+        }
+        for &(p, exponent) in factors {
+            for _ in 0..exponent {
+                if (*self * (order / p)).is_infinity() {
+                    order /= p;
+                } else {
+                    break;
+                }
+            }
+        }
+        order
+    }
+
     // x*self = target
     pub fn solve_dlp_brute_force(&self, target: ECPoint) -> Option<u64> {
         let mut xp = *self;
@@ -107,9 +243,109 @@ impl ECPoint {
         None
     }
 
+    // Montgomery ladder: computes x(k*self) using only x-coordinates.
+    // Returns None when k*self is the point at infinity.
+    pub fn ladder_x(&self, k: u64) -> Option<Felt> {
+        let (r0, _) = self.ladder(k);
+        Self::affine_x(r0)
+    }
+
+    // Montgomery ladder scalar multiplication. Every bit of k costs one
+    // doubling and one differential addition; y is recovered at the end
+    // (Okeya-Sakurai) so the result matches `k * self`.
+    pub fn montgomery_ladder(&self, k: u64) -> ECPoint {
+        if self.infinity {
+            return *self;
+        }
+
+        let (r0, r1) = self.ladder(k);
+        let infinity = ECPoint::infinity(self.a, self.b);
+        let x0 = match Self::affine_x(r0) {
+            Some(x0) => x0,
+            None => return infinity,
+        };
+        // (k + 1)P = 0 means kP = -P
+        let x1 = match Self::affine_x(r1) {
+            Some(x1) => x1,
+            None => return -*self,
+        };
+
+        let felt_2 = Felt::new(2, self.a.modulus());
+        let num =
+            felt_2 * self.b + (self.a + self.x * x0) * (self.x + x0) - x1 * (self.x - x0).pow(2);
+        let y0 = num / (felt_2 * self.y);
+
+        ECPoint::new(x0, y0, self.a, self.b).unwrap()
+    }
+
+    // Returns (k*P, (k+1)*P) in x-only projective coordinates (X : Z)
+    fn ladder(&self, k: u64) -> ((Felt, Felt), (Felt, Felt)) {
+        let modulus = self.a.modulus();
+        let zero = Felt::new(0, modulus);
+        let one = Felt::new(1, modulus);
+
+        if self.infinity {
+            return ((one, zero), (one, zero));
+        }
+
+        let mut r0 = (one, zero);
+        let mut r1 = (self.x, one);
+        for i in (0..u64::BITS).rev() {
+            if (k >> i) & 1 == 0 {
+                r1 = self.x_add(r0, r1);
+                r0 = self.x_double(r0);
+            } else {
+                r0 = self.x_add(r0, r1);
+                r1 = self.x_double(r1);
+            }
+        }
+
+        (r0, r1)
+    }
+
+    fn affine_x((x, z): (Felt, Felt)) -> Option<Felt> {
+        if z.value() == 0 {
+            None
+        } else {
+            Some(x / z)
+        }
+    }
+
+    // x(2P) = ((x^2 - a)^2 - 8bx) / (4(x^3 + ax + b))
+    fn x_double(&self, (x, z): (Felt, Felt)) -> (Felt, Felt) {
+        let modulus = self.a.modulus();
+        let felt_4 = Felt::new(4, modulus);
+        let felt_8 = Felt::new(8, modulus);
+
+        let xx = x.pow(2);
+        let zz = z.pow(2);
+        let new_x = (xx - self.a * zz).pow(2) - felt_8 * self.b * x * zz * z;
+        let new_z = felt_4 * z * (xx * x + self.a * x * zz + self.b * zz * z);
+
+        (new_x, new_z)
+    }
+
+    // Differential addition of P and Q, where Q - P = self:
+    // x(P+Q) + x(Q-P) = 2((x_P + x_Q)(x_P x_Q + a) + 2b) / (x_P - x_Q)^2
+    fn x_add(&self, (xp, zp): (Felt, Felt), (xq, zq): (Felt, Felt)) -> (Felt, Felt) {
+        let modulus = self.a.modulus();
+        let felt_2 = Felt::new(2, modulus);
+
+        // P = 0 or Q = 0: the sum is ±self, both with x = x(self)
+        if zp.value() == 0 || zq.value() == 0 {
+            return (self.x, Felt::new(1, modulus));
+        }
+
+        let zpzq = zp * zq;
+        let u = (xp * zq - xq * zp).pow(2);
+        let num = felt_2
+            * ((xp * zq + xq * zp) * (xp * xq + self.a * zpzq) + felt_2 * self.b * zpzq.pow(2));
+
+        (num - self.x * u, u)
+    }
+
     // Naive implementation of getting all points on the curve
-    #[allow(dead_code)]
-    fn get_all_points(a: Felt, b: Felt) -> HashSet<ECPoint> {
+    pub fn get_all_points(a: Felt, b: Felt) -> HashSet<ECPoint> {
         let mut points = HashSet::new();
         points.insert(ECPoint::infinity(a, b));
 
@@ -127,6 +363,207 @@ impl ECPoint {
 
         points
     }
+
+    // SEC1 encoding (section 2.3.3), infinity is the single byte 0x00
+    pub fn to_sec1(&self, format: Sec1Format) -> Vec<u8> {
+        if self.infinity {
+            return vec![0x00];
+        }
+
+        let y_is_odd = self.y.value() % 2 == 1;
+        let mut bytes = match format {
+            Sec1Format::Compressed => vec![0x02 | y_is_odd as u8],
+            Sec1Format::Uncompressed => vec![0x04],
+            Sec1Format::Hybrid => vec![0x06 | y_is_odd as u8],
+        };
+
+        let length = ECPoint::sec1_field_length(self.a.modulus());
+        bytes.extend_from_slice(&self.x.value().to_be_bytes()[8 - length..]);
+        if format != Sec1Format::Compressed {
+            bytes.extend_from_slice(&self.y.value().to_be_bytes()[8 - length..]);
+        }
+        bytes
+    }
+
+    // SEC1 decoding (section 2.3.4) onto the curve y^2 = x^3 + ax + b over
+    // F_p for an odd prime p, decompression needs square roots
+    pub fn from_sec1(bytes: &[u8], a: Felt, b: Felt) -> Result<ECPoint, ECError> {
+        let modulus = a.modulus();
+        if modulus == 2 || !is_prime(modulus) {
+            return Err(ECError::UnsupportedModulus(modulus));
+        }
+        let length = ECPoint::sec1_field_length(modulus);
+        let invalid = |reason: &str| Err(ECError::InvalidSec1Encoding(reason.to_string()));
+
+        let (&prefix, rest) = match bytes.split_first() {
+            Some(split) => split,
+            None => return invalid("empty input"),
+        };
+        let expected_length = match prefix {
+            0x00 => 0,
+            0x02 | 0x03 => length,
+            0x04 | 0x06 | 0x07 => 2 * length,
+            _ => return invalid(&format!("unknown prefix 0x{:02x}", prefix)),
+        };
+        if rest.len() != expected_length {
+            return invalid(&format!(
+                "expected {} bytes after prefix 0x{:02x}, got {}",
+                expected_length,
+                prefix,
+                rest.len()
+            ));
+        }
+        if prefix == 0x00 {
+            return Ok(ECPoint::infinity(a, b));
+        }
+
+        let read = |chunk: &[u8]| {
+            let value = chunk.iter().fold(0, |acc, &byte| (acc << 8) | byte as u64);
+            if value < modulus {
+                Ok(Felt::new(value, modulus))
+            } else {
+                Err(ECError::InvalidSec1Encoding(format!(
+                    "coordinate {} is not reduced mod {}",
+                    value, modulus
+                )))
+            }
+        };
+        let x = read(&rest[..length])?;
+
+        let y = if prefix == 0x04 || prefix == 0x06 || prefix == 0x07 {
+            let y = read(&rest[length..])?;
+            if prefix != 0x04 && (y.value() % 2 == 1) != (prefix == 0x07) {
+                return invalid("hybrid prefix does not match the parity of y");
+            }
+            y
+        } else {
+            let y = match (x.pow(3) + a * x + b).sqrt() {
+                Some(y) => y,
+                None => return invalid("x is not the abscissa of a point on the curve"),
+            };
+            if (y.value() % 2 == 1) == (prefix == 0x03) {
+                y
+            } else if y.value() != 0 {
+                -y
+            } else {
+                return invalid("y = 0 has no odd root");
+            }
+        };
+
+        ECPoint::new(x, y, a, b).or_else(|_| {
+            invalid(&format!(
+                "({}, {}) is not on the curve",
+                x.value(),
+                y.value()
+            ))
+        })
+    }
+
+    // Parses the Display format "(x, y)" or "Infinity" onto the curve
+    // y^2 = x^3 + ax + b. Coordinates may be hex (0x) or negative.
+    pub fn parse(s: &str, a: Felt, b: Felt) -> Result<ECPoint, ECError> {
+        let mut parser = Parser::new(s);
+        let parse = |parser: &mut Parser| {
+            let point = ECPoint::parse_point(parser)?;
+            parser.expect_end()?;
+            ECPoint::from_parsed(point, a, b)
+        };
+        parse(&mut parser).map_err(|(position, message)| ECError::Parse(position, message))
+    }
+
+    // "(x, y) on y^2 = x^3 + ax + b (mod p)", the format read by FromStr
+    pub fn to_qualified_string(&self) -> String {
+        format!(
+            "{} on y^2 = x^3 + {}x + {} (mod {})",
+            self,
+            self.a.value(),
+            self.b.value(),
+            self.a.modulus()
+        )
+    }
+
+    fn parse_point(parser: &mut Parser) -> ParseResult<ParsedPoint> {
+        parser.skip_whitespace();
+        let start = parser.position();
+        if parser.consume("Infinity") {
+            return Ok((start, None));
+        }
+        if !parser.consume("(") {
+            return parser.error(start, "expected \"(x, y)\" or \"Infinity\"");
+        }
+        let (_, x_negative, x) = parser.integer()?;
+        parser.expect(",")?;
+        let (_, y_negative, y) = parser.integer()?;
+        parser.expect(")")?;
+        Ok((start, Some([(x_negative, x), (y_negative, y)])))
+    }
+
+    fn from_parsed((start, coordinates): ParsedPoint, a: Felt, b: Felt) -> ParseResult<ECPoint> {
+        let [(x_negative, x), (y_negative, y)] = match coordinates {
+            Some(coordinates) => coordinates,
+            None => return Ok(ECPoint::infinity(a, b)),
+        };
+        let modulus = a.modulus();
+        let x = signed_felt(x_negative, x, modulus);
+        let y = signed_felt(y_negative, y, modulus);
+        ECPoint::new(x, y, a, b).map_err(|e| (start, e.to_string()))
+    }
+
+    // "y^2 = x^3 + ax + b (mod p)", terms may be omitted or reordered, or
+    // the name of a curve in the registry
+    fn parse_curve(parser: &mut Parser) -> ParseResult<(Felt, Felt)> {
+        parser.skip_whitespace();
+        if !parser.rest().starts_with("y^2") {
+            let (start, name) = parser.identifier()?;
+            return match named_curve(name) {
+                Some(curve) => curve.ec_coefficients().map_err(|e| (start, e.to_string())),
+                None => parser.error(start, &format!("unknown curve {}", name)),
+            };
+        }
+
+        parser.expect("y^2")?;
+        parser.expect("=")?;
+        parser.expect("x^3")?;
+
+        let mut a = None;
+        let mut b = None;
+        loop {
+            let negative = if parser.consume("+") {
+                false
+            } else if parser.consume("-") {
+                true
+            } else {
+                break;
+            };
+
+            parser.skip_whitespace();
+            let start = parser.position();
+            let (negative, value) = if parser.rest().starts_with('x') {
+                (negative, 1)
+            } else {
+                let (_, value_negative, value) = parser.integer()?;
+                (negative != value_negative, value)
+            };
+
+            let term = if parser.consume("x") { &mut a } else { &mut b };
+            if term.is_some() {
+                return parser.error(start, "duplicate term");
+            }
+            *term = Some((negative, value));
+        }
+
+        let modulus = parser.modulus()?;
+        let felt = |term: Option<(bool, u64)>| match term {
+            Some((negative, value)) => signed_felt(negative, value, modulus),
+            None => Felt::new(0, modulus),
+        };
+        Ok((felt(a), felt(b)))
+    }
+
+    // Bytes needed for an element of F_p
+    fn sec1_field_length(modulus: u64) -> usize {
+        (64 - modulus.leading_zeros() as usize).div_ceil(8)
+    }
 }
 
 impl Add for ECPoint {
@@ -211,6 +648,24 @@ impl Mul<ECPoint> for u64 {
     }
 }
 
+// Parses "<point> on <curve>" as written by to_qualified_string, the curve
+// may also be a registry name: "(13, 15) on toy-43"
+impl FromStr for ECPoint {
+    type Err = ECError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let parse = |parser: &mut Parser| {
+            let point = ECPoint::parse_point(parser)?;
+            parser.expect("on")?;
+            let (a, b) = ECPoint::parse_curve(parser)?;
+            parser.expect_end()?;
+            ECPoint::from_parsed(point, a, b)
+        };
+        parse(&mut parser).map_err(|(position, message)| ECError::Parse(position, message))
+    }
+}
+
 impl Display for ECPoint {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if self.infinity {
@@ -467,6 +922,72 @@ mod test {
         assert_eq!(k * p, kp);
     }
 
+    #[test]
+    fn test_montgomery_ladder_matches_multiplication() {
+        let modulus = 37;
+        let a = Felt::new(3, modulus);
+        let b = Felt::new(7, modulus);
+        let x = Felt::new(18, modulus);
+        let y = Felt::new(26, modulus);
+        let p = ECPoint::new(x, y, a, b).unwrap();
+
+        for k in 0..200 {
+            assert_eq!(p.montgomery_ladder(k), k * p);
+        }
+    }
+
+    #[test]
+    fn test_montgomery_ladder() {
+        let modulus = 1021;
+        let a = -Felt::new(3, modulus);
+        let b = -Felt::new(3, modulus);
+        let x = Felt::new(379, modulus);
+        let y = Felt::new(1011, modulus);
+        let p = ECPoint::new(x, y, a, b).unwrap();
+        let kp = ECPoint::new(Felt::new(388, modulus), Felt::new(60, modulus), a, b).unwrap();
+        assert_eq!(p.montgomery_ladder(655), kp);
+        assert_eq!(p.montgomery_ladder(u64::MAX), u64::MAX * p);
+    }
+
+    #[test]
+    fn test_montgomery_ladder_point_with_x_zero() {
+        let modulus = 43;
+        let a = Felt::new(0, modulus);
+        let b = Felt::new(6, modulus);
+        let x = Felt::new(0, modulus);
+        let y = Felt::new(36, modulus);
+        let p = ECPoint::new(x, y, a, b).unwrap();
+
+        for k in 0..50 {
+            assert_eq!(p.montgomery_ladder(k), k * p);
+        }
+    }
+
+    #[test]
+    fn test_montgomery_ladder_point_of_order_two() {
+        let modulus = 61;
+        let a = -Felt::new(1, modulus);
+        let b = Felt::new(0, modulus);
+        let p = ECPoint::new(Felt::new(1, modulus), Felt::new(0, modulus), a, b).unwrap();
+
+        assert_eq!(p.montgomery_ladder(2), ECPoint::infinity(a, b));
+        assert_eq!(p.montgomery_ladder(3), p);
+    }
+
+    #[test]
+    fn test_ladder_x() {
+        let modulus = 37;
+        let a = Felt::new(7, modulus);
+        let b = Felt::new(13, modulus);
+        let x = Felt::new(5, modulus);
+        let y = Felt::new(5, modulus);
+        let p = ECPoint::new(x, y, a, b).unwrap();
+
+        assert_eq!(p.ladder_x(10), Some(Felt::new(22, modulus)));
+        assert_eq!(p.ladder_x(0), None);
+        assert_eq!(p.ladder_x(p.order()), None);
+    }
+
     #[test]
     fn test_get_all_points_simple() {
         let modulus = 7;
@@ -499,6 +1020,146 @@ mod test {
         assert_eq!(order, 1039);
     }
 
+    #[test]
+    fn test_order_dividing() {
+        let modulus = 43;
+        let a = Felt::new(0, modulus);
+        let b = Felt::new(6, modulus);
+        let g1 = ECPoint::new(Felt::new(13, modulus), Felt::new(15, modulus), a, b).unwrap();
+        let g2 = ECPoint::new(Felt::new(9, modulus), Felt::new(2, modulus), a, b).unwrap();
+        assert_eq!(g1.order_dividing(39), 13);
+        assert_eq!(g2.order_dividing(39), 39);
+        assert_eq!((g2 * 13).order_dividing(39), 3);
+        assert_eq!(ECPoint::infinity(a, b).order_dividing(39), 1);
+        assert_eq!(g2.order(), 39);
+    }
+
+    #[test]
+    #[should_panic(expected = "The multiple of the order must be nonzero")]
+    fn test_order_dividing_zero_should_panic() {
+        let modulus = 43;
+        let a = Felt::new(0, modulus);
+        let b = Felt::new(6, modulus);
+        let g = ECPoint::new(Felt::new(13, modulus), Felt::new(15, modulus), a, b).unwrap();
+        g.order_dividing(0);
+    }
+
+    #[test]
+    fn test_order_from_factors() {
+        let modulus = 1021;
+        let a = -Felt::new(3, modulus);
+        let b = -Felt::new(3, modulus);
+        let p = ECPoint::new(Felt::new(379, modulus), Felt::new(1011, modulus), a, b).unwrap();
+        // Any multiple of the order works, here 2^10 * 3^5 * 1039^2
+        let factors = [(2, 10), (3, 5), (1039, 2)];
+        assert_eq!(p.order_from_factors(&factors), 1039);
+        assert_eq!(p.order_dividing(1039 << 20), 1039);
+    }
+
+    #[test]
+    #[should_panic(expected = "The factorization must multiply to a u64")]
+    fn test_order_from_factors_overflow_should_panic() {
+        let modulus = 1021;
+        let a = -Felt::new(3, modulus);
+        let b = -Felt::new(3, modulus);
+        let p = ECPoint::new(Felt::new(379, modulus), Felt::new(1011, modulus), a, b).unwrap();
+        p.order_from_factors(&[(1039, 1), (2, 64)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The factorization is not of a multiple of the order")]
+    fn test_order_from_factors_not_a_multiple_should_panic() {
+        let modulus = 1021;
+        let a = -Felt::new(3, modulus);
+        let b = -Felt::new(3, modulus);
+        let p = ECPoint::new(Felt::new(379, modulus), Felt::new(1011, modulus), a, b).unwrap();
+        p.order_from_factors(&[(2, 10), (3, 5)]);
+    }
+
+    #[test]
+    fn test_j_invariant() {
+        let modulus = 43;
+        let p = ECPoint::new(
+            Felt::new(13, modulus),
+            Felt::new(15, modulus),
+            Felt::new(0, modulus),
+            Felt::new(6, modulus),
+        )
+        .unwrap();
+        assert_eq!(p.j_invariant(), Felt::new(0, modulus));
+
+        let modulus = 61;
+        let p = ECPoint::new(
+            Felt::new(8, modulus),
+            Felt::new(4, modulus),
+            -Felt::new(1, modulus),
+            Felt::new(0, modulus),
+        )
+        .unwrap();
+        assert_eq!(p.j_invariant(), Felt::new(1728, modulus));
+    }
+
+    #[test]
+    fn test_isomorphism_maps_points() {
+        let modulus = 1021;
+        let a = -Felt::new(3, modulus);
+        let b = -Felt::new(3, modulus);
+        let u = Felt::new(10, modulus);
+        let a2 = a / u.pow(4);
+        let b2 = b / u.pow(6);
+
+        let change = ECPoint::isomorphism(a, b, a2, b2).unwrap();
+        let p = ECPoint::new(Felt::new(379, modulus), Felt::new(1011, modulus), a, b).unwrap();
+        let q = p.map(change, a2, b2).unwrap();
+
+        assert_eq!((655 * p).map(change, a2, b2).unwrap(), 655 * q);
+        assert_eq!(q.j_invariant(), p.j_invariant());
+    }
+
+    #[test]
+    fn test_different_j_invariants_are_not_isomorphic() {
+        let modulus = 37;
+        let a = Felt::new(3, modulus);
+        let b = Felt::new(7, modulus);
+        assert!(!ECPoint::is_isomorphic(
+            a,
+            b,
+            Felt::new(7, modulus),
+            Felt::new(13, modulus)
+        ));
+        assert!(ECPoint::is_isomorphic(a, b, a, b));
+    }
+
+    #[test]
+    fn test_count_points() {
+        let modulus = 1021;
+        let a = -Felt::new(3, modulus);
+        let b = -Felt::new(3, modulus);
+        assert_eq!(ECPoint::count_points(a, b), 1039);
+        assert_eq!(ECPoint::count_points(Felt::new(2, 7), Felt::new(3, 7)), 6);
+    }
+
+    #[test]
+    fn test_quadratic_twist() {
+        let modulus = 1021;
+        let a = -Felt::new(3, modulus);
+        let b = -Felt::new(3, modulus);
+        let (twist_a, twist_b) = ECPoint::quadratic_twist(a, b);
+
+        assert!(!ECPoint::is_isomorphic(a, b, twist_a, twist_b));
+        assert_eq!(ECPoint::twist_order(a, b), 1005);
+        assert_eq!(
+            ECPoint::get_all_points(twist_a, twist_b).len() as u64,
+            ECPoint::twist_order(a, b)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Every element of F_2 is a square")]
+    fn test_quadratic_twist_over_f2_should_panic() {
+        ECPoint::quadratic_twist(Felt::new(1, 2), Felt::new(1, 2));
+    }
+
     #[test]
     fn test_solve_dlp_brute_force() {
         let modulus = 1021;
@@ -560,4 +1221,146 @@ mod test {
         let p1 = ECPoint::infinity(a, b);
         assert_eq!(format!("{}", p1), "Infinity");
     }
+
+    fn sec1_curve() -> (Felt, Felt) {
+        (-Felt::new(3, 1021), -Felt::new(3, 1021))
+    }
+
+    #[test]
+    fn test_sec1_encodings() {
+        let (a, b) = sec1_curve();
+        let p = ECPoint::new(Felt::new(379, 1021), Felt::new(1011, 1021), a, b).unwrap();
+
+        assert_eq!(p.to_sec1(Sec1Format::Compressed), vec![0x03, 0x01, 0x7b]);
+        assert_eq!(
+            p.to_sec1(Sec1Format::Uncompressed),
+            vec![0x04, 0x01, 0x7b, 0x03, 0xf3]
+        );
+        assert_eq!(
+            (-p).to_sec1(Sec1Format::Hybrid),
+            vec![0x06, 0x01, 0x7b, 0x00, 0x0a]
+        );
+        assert_eq!(
+            ECPoint::infinity(a, b).to_sec1(Sec1Format::Compressed),
+            vec![0x00]
+        );
+    }
+
+    #[test]
+    fn test_sec1_round_trip() {
+        let (a, b) = sec1_curve();
+        for p in ECPoint::get_all_points(a, b) {
+            for format in [
+                Sec1Format::Compressed,
+                Sec1Format::Uncompressed,
+                Sec1Format::Hybrid,
+            ] {
+                assert_eq!(ECPoint::from_sec1(&p.to_sec1(format), a, b).unwrap(), p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sec1_decoding_errors() {
+        let (a, b) = sec1_curve();
+        let decode = |bytes: &[u8]| ECPoint::from_sec1(bytes, a, b);
+
+        assert!(decode(&[]).is_err());
+        assert!(decode(&[0x05, 0x01, 0x7b]).is_err());
+        // Wrong length
+        assert!(decode(&[0x02, 0x01]).is_err());
+        assert!(decode(&[0x00, 0x00]).is_err());
+        // x = 1021 is not reduced
+        assert!(decode(&[0x02, 0x03, 0xfd]).is_err());
+        // Not on the curve
+        assert!(decode(&[0x04, 0x01, 0x7b, 0x03, 0xf2]).is_err());
+        // Hybrid parity does not match y = 1011
+        assert!(decode(&[0x06, 0x01, 0x7b, 0x03, 0xf3]).is_err());
+    }
+
+    #[test]
+    fn test_sec1_compressed_x_not_on_curve() {
+        let (a, b) = sec1_curve();
+        let x = (0..1021)
+            .find(|&x| {
+                let x = Felt::new(x, 1021);
+                (x.pow(3) + a * x + b).sqrt().is_none()
+            })
+            .unwrap();
+        let bytes = [0x02, (x >> 8) as u8, x as u8];
+        assert!(matches!(
+            ECPoint::from_sec1(&bytes, a, b),
+            Err(ECError::InvalidSec1Encoding(_))
+        ));
+    }
+
+    #[test]
+    fn test_sec1_unsupported_modulus() {
+        for modulus in [2, 15] {
+            let (a, b) = (Felt::new(1, modulus), Felt::new(1, modulus));
+            assert!(matches!(
+                ECPoint::from_sec1(&[0x02, 0x01], a, b),
+                Err(ECError::UnsupportedModulus(m)) if m == modulus
+            ));
+        }
+    }
+
+    fn parse_error_position(result: Result<ECPoint, ECError>) -> usize {
+        match result {
+            Err(ECError::Parse(position, _)) => position,
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let (a, b) = (Felt::new(3, 37), Felt::new(7, 37));
+        for p in ECPoint::get_all_points(a, b) {
+            assert_eq!(ECPoint::parse(&p.to_string(), a, b).unwrap(), p);
+            assert_eq!(p.to_qualified_string().parse::<ECPoint>().unwrap(), p);
+        }
+    }
+
+    #[test]
+    fn test_parse_qualified() {
+        let a = -Felt::new(3, 1021);
+        let b = -Felt::new(3, 1021);
+        let p = ECPoint::new(Felt::new(379, 1021), Felt::new(1011, 1021), a, b).unwrap();
+
+        for s in [
+            "(379, 1011) on y^2 = x^3 + 1018x + 1018 (mod 1021)",
+            "(379, -10) on y^2 = x^3 - 3x - 3 (mod 1021)",
+            "(0x17b, 0x3f3) on y^2=x^3-3-3x(mod 0x3fd)",
+            "(379, 1011) on toy-1021",
+        ] {
+            assert_eq!(s.parse::<ECPoint>().unwrap(), p, "{}", s);
+        }
+        assert_eq!(
+            "Infinity on y^2 = x^3 + x (mod 7)"
+                .parse::<ECPoint>()
+                .unwrap(),
+            ECPoint::infinity(Felt::new(1, 7), Felt::new(0, 7))
+        );
+        assert_eq!(
+            "(13, 15) on TOY-43".parse::<ECPoint>().unwrap(),
+            named_curve("toy-43").unwrap().ec_generator().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error_positions() {
+        let (a, b) = (Felt::new(3, 37), Felt::new(7, 37));
+        assert_eq!(parse_error_position(ECPoint::parse("(18 26)", a, b)), 4);
+        assert_eq!(parse_error_position(ECPoint::parse("  (18, 27)", a, b)), 2);
+        assert_eq!(parse_error_position(ECPoint::parse("Zero", a, b)), 0);
+
+        let parse = |s: &str| parse_error_position(s.parse::<ECPoint>());
+        assert_eq!(parse("(18, 26)"), 8);
+        assert_eq!(parse("(18, 26) on y^2 = x^3 + 3x + 7x (mod 37)"), 29);
+        assert_eq!(parse("(18, 26) on y^2 = x^3 + 3x + 7 (mod 37"), 38);
+        assert_eq!(parse("(18, 26) on y^2 = x^3 + 3x + 7 (mod 37) !"), 40);
+        assert_eq!(parse("(18, 26) on secp256k3"), 12);
+        // Too large for ECPoint
+        assert_eq!(parse("(18, 26) on secp256k1"), 12);
+    }
 }
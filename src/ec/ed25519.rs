@@ -0,0 +1,211 @@
+use std::ops::{Add, Neg};
+
+use crate::felt::fe25519::Fe25519;
+
+// d = -121665 / 121666 (mod 2^255 - 19)
+const D: [u8; 32] = [
+    0xa3, 0x78, 0x59, 0x13, 0xca, 0x4d, 0xeb, 0x75, 0xab, 0xd8, 0x41, 0x41, 0x4d, 0x0a, 0x70, 0x00,
+    0x98, 0xe8, 0x79, 0x77, 0x79, 0x40, 0xc7, 0x8c, 0x73, 0xfe, 0x6f, 0x2b, 0xee, 0x6c, 0x03, 0x52,
+];
+
+// x-coordinate of the base point, the even root of x^2 = (y^2 - 1)/(dy^2 + 1)
+const BASEPOINT_X: [u8; 32] = [
+    0x1a, 0xd5, 0x25, 0x8f, 0x60, 0x2d, 0x56, 0xc9, 0xb2, 0xa7, 0x25, 0x95, 0x60, 0xc7, 0x2c, 0x69,
+    0x5c, 0xdc, 0xd6, 0xfd, 0x31, 0xe2, 0xa4, 0xc0, 0xfe, 0x53, 0x6e, 0xcd, 0xd3, 0x36, 0x69, 0x21,
+];
+
+// Order of the base point, L = 2^252 + 27742317777372353535851937790883648493
+pub const ED25519_ORDER: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+// Point on the Ed25519 curve -x^2 + y^2 = 1 + dx^2y^2 (mod 2^255 - 19) in
+// extended coordinates. This is EdwardsPoint specialised to Fe25519, since
+// Felt cannot hold a 255-bit modulus.
+#[derive(Debug, Clone, Copy)]
+pub struct Ed25519Point {
+    x: Fe25519,
+    y: Fe25519,
+    z: Fe25519,
+    t: Fe25519,
+}
+
+impl Ed25519Point {
+    pub fn identity() -> Self {
+        Ed25519Point {
+            x: Fe25519::zero(),
+            y: Fe25519::one(),
+            z: Fe25519::one(),
+            t: Fe25519::zero(),
+        }
+    }
+
+    // (x, 4/5)
+    pub fn basepoint() -> Self {
+        let x = Fe25519::from_bytes(&BASEPOINT_X);
+        let y = Fe25519::from_u64(4) * Fe25519::from_u64(5).invert();
+        Ed25519Point {
+            x,
+            y,
+            z: Fe25519::one(),
+            t: x * y,
+        }
+    }
+
+    pub fn x(&self) -> Fe25519 {
+        self.x * self.z.invert()
+    }
+
+    pub fn y(&self) -> Fe25519 {
+        self.y * self.z.invert()
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        let (x, y) = (self.x(), self.y());
+        let d = Fe25519::from_bytes(&D);
+        y.square() - x.square() == Fe25519::one() + d * x.square() * y.square()
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.x.is_zero() && self.y == self.z
+    }
+
+    // RFC 8032 encoding: y in little-endian with the sign of x in the top bit
+    pub fn compress(&self) -> [u8; 32] {
+        let mut bytes = self.y().to_bytes();
+        bytes[31] |= (self.x().to_bytes()[0] & 1) << 7;
+        bytes
+    }
+
+    // u-coordinate of the birationally equivalent Curve25519 point,
+    // u = (1 + y) / (1 - y)
+    pub fn to_montgomery_u(&self) -> [u8; 32] {
+        let u = (self.z + self.y) * (self.z - self.y).invert();
+        u.to_bytes()
+    }
+
+    // k is a little-endian 256-bit integer
+    pub fn scalar_mul(&self, k: &[u8; 32]) -> Self {
+        let mut result = Ed25519Point::identity();
+        for i in (0..256).rev() {
+            result = result + result;
+            if (k[i / 8] >> (i % 8)) & 1 == 1 {
+                result = result + *self;
+            }
+        }
+        result
+    }
+}
+
+impl Add for Ed25519Point {
+    type Output = Self;
+
+    // Same unified formula as EdwardsPoint with a = -1
+    fn add(self, other: Self) -> Self {
+        let d = Fe25519::from_bytes(&D);
+
+        let a = self.x * other.x;
+        let b = self.y * other.y;
+        let c = d * self.t * other.t;
+        let dd = self.z * other.z;
+        let e = (self.x + self.y) * (other.x + other.y) - a - b;
+        let f = dd - c;
+        let g = dd + c;
+        let h = b + a;
+
+        Ed25519Point {
+            x: e * f,
+            y: g * h,
+            z: f * g,
+            t: e * h,
+        }
+    }
+}
+
+impl Neg for Ed25519Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Ed25519Point {
+            x: -self.x,
+            t: -self.t,
+            ..self
+        }
+    }
+}
+
+impl PartialEq for Ed25519Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+    }
+}
+
+impl Eq for Ed25519Point {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ec::x25519::{x25519, X25519_BASEPOINT};
+
+    fn from_hex(hex: &str) -> [u8; 32] {
+        let mut bytes = [0_u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_d() {
+        let d = Fe25519::from_bytes(&D);
+        assert_eq!(d * Fe25519::from_u64(121666), -Fe25519::from_u64(121665));
+    }
+
+    #[test]
+    fn test_basepoint_on_curve() {
+        let b = Ed25519Point::basepoint();
+        assert!(b.is_on_curve());
+        assert!((b + b).is_on_curve());
+    }
+
+    #[test]
+    fn test_basepoint_encoding() {
+        let b = Ed25519Point::basepoint();
+        assert_eq!(
+            b.compress(),
+            from_hex("5866666666666666666666666666666666666666666666666666666666666666")
+        );
+    }
+
+    #[test]
+    fn test_basepoint_order() {
+        let b = Ed25519Point::basepoint();
+        assert!(b.scalar_mul(&ED25519_ORDER).is_identity());
+        assert!(!b.scalar_mul(&[8; 32]).is_identity());
+    }
+
+    #[test]
+    fn test_add_identity_and_inverse() {
+        let b = Ed25519Point::basepoint();
+        assert_eq!(b + Ed25519Point::identity(), b);
+        assert!((b + (-b)).is_identity());
+    }
+
+    // The base point maps to u = 9 on Curve25519, so [k]B must agree with
+    // X25519 on the RFC 7748 keys
+    #[test]
+    fn test_scalar_mul_matches_x25519() {
+        let b = Ed25519Point::basepoint();
+        assert_eq!(b.to_montgomery_u(), X25519_BASEPOINT);
+
+        let mut alice_sk =
+            from_hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let alice_public = x25519(alice_sk, X25519_BASEPOINT);
+        alice_sk[0] &= 248;
+        alice_sk[31] &= 127;
+        alice_sk[31] |= 64;
+
+        assert_eq!(b.scalar_mul(&alice_sk).to_montgomery_u(), alice_public);
+    }
+}
@@ -0,0 +1,432 @@
+use std::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Mul, Neg},
+};
+
+use crate::felt::felt::Felt;
+
+use super::{ec_errors::ECError, ec_point::ECPoint, montgomery_point::MontgomeryPoint};
+
+// Point on the twisted Edwards curve ax^2 + y^2 = 1 + dx^2y^2 in extended
+// coordinates (X : Y : Z : T) with x = X/Z, y = Y/Z and xy = T/Z.
+// The neutral element (0, 1) is an ordinary point of the curve, so there is
+// no infinity flag as in ECPoint.
+#[derive(Debug, Clone, Copy)]
+pub struct EdwardsPoint {
+    x: Felt,
+    y: Felt,
+    z: Felt,
+    t: Felt,
+    a: Felt,
+    d: Felt,
+}
+
+impl EdwardsPoint {
+    pub fn new(x: Felt, y: Felt, a: Felt, d: Felt) -> Result<Self, ECError> {
+        Self::verify_curve(a, d)?;
+
+        let lhs = a * x.pow(2) + y.pow(2);
+        let rhs = Felt::new(1, a.modulus()) + d * x.pow(2) * y.pow(2);
+        if lhs != rhs {
+            return Err(ECError::PointNotOnEdwardsCurve(
+                x.value(),
+                y.value(),
+                a.value(),
+                d.value(),
+            ));
+        }
+
+        Ok(EdwardsPoint {
+            x,
+            y,
+            z: Felt::new(1, a.modulus()),
+            t: x * y,
+            a,
+            d,
+        })
+    }
+
+    // ad(a - d) != 0
+    fn verify_curve(a: Felt, d: Felt) -> Result<(), ECError> {
+        if a.value() == 0 || d.value() == 0 || a == d {
+            return Err(ECError::SingularEdwardsCurve(a.value(), d.value()));
+        }
+        Ok(())
+    }
+
+    pub fn identity(a: Felt, d: Felt) -> EdwardsPoint {
+        let modulus = a.modulus();
+        EdwardsPoint {
+            x: Felt::new(0, modulus),
+            y: Felt::new(1, modulus),
+            z: Felt::new(1, modulus),
+            t: Felt::new(0, modulus),
+            a,
+            d,
+        }
+    }
+
+    // The unified addition law is complete (has no exceptional inputs)
+    // when a is a square and d is not
+    pub fn has_complete_addition(a: Felt, d: Felt) -> bool {
+        a.sqrt().is_some() && d.sqrt().is_none()
+    }
+
+    pub fn x(&self) -> Felt {
+        self.x / self.z
+    }
+
+    pub fn y(&self) -> Felt {
+        self.y / self.z
+    }
+
+    pub fn a(&self) -> Felt {
+        self.a
+    }
+
+    pub fn d(&self) -> Felt {
+        self.d
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.x.value() == 0 && self.y == self.z
+    }
+
+    // Montgomery coefficients of the birationally equivalent curve:
+    // A = 2(a + d) / (a - d), B = 4 / (a - d)
+    pub fn montgomery_coefficients(a: Felt, d: Felt) -> (Felt, Felt) {
+        let modulus = a.modulus();
+        let felt_2 = Felt::new(2, modulus);
+        let felt_4 = Felt::new(4, modulus);
+
+        (felt_2 * (a + d) / (a - d), felt_4 / (a - d))
+    }
+
+    // (x, y) -> ((1 + y) / (1 - y), (1 + y) / ((1 - y)x))
+    // The identity maps to infinity and (0, -1) to (0, 0)
+    pub fn to_montgomery(&self) -> MontgomeryPoint {
+        let (mont_a, mont_b) = Self::montgomery_coefficients(self.a, self.d);
+        let modulus = self.a.modulus();
+        let zero = Felt::new(0, modulus);
+        let one = Felt::new(1, modulus);
+
+        if self.is_identity() {
+            return MontgomeryPoint::infinity(mont_a, mont_b);
+        }
+
+        let x = self.x();
+        let y = self.y();
+        if x == zero {
+            return MontgomeryPoint::new(zero, zero, mont_a, mont_b).unwrap();
+        }
+
+        let u = (one + y) / (one - y);
+        let v = u / x;
+
+        MontgomeryPoint::new(u, v, mont_a, mont_b).unwrap()
+    }
+
+    // Inverse of to_montgomery onto the Edwards curve with coefficients
+    // (a, d): (u, v) -> (u / v, (u - 1) / (u + 1))
+    pub fn from_montgomery(point: MontgomeryPoint, a: Felt, d: Felt) -> Result<Self, ECError> {
+        Self::verify_curve(a, d)?;
+        let modulus = a.modulus();
+        let zero = Felt::new(0, modulus);
+        let one = Felt::new(1, modulus);
+
+        let (mont_a, mont_b) = Self::montgomery_coefficients(a, d);
+        if (point.a(), point.b()) != (mont_a, mont_b) {
+            return Err(ECError::CurveMismatch(
+                format!(
+                    "{}y^2 = x^3 + {}x^2 + x",
+                    point.b().value(),
+                    point.a().value()
+                ),
+                format!("{}y^2 = x^3 + {}x^2 + x", mont_b.value(), mont_a.value()),
+            ));
+        }
+        if point.is_infinity() {
+            return Ok(EdwardsPoint::identity(a, d));
+        }
+
+        let u = point.x();
+        let v = point.y();
+        if u == zero {
+            return EdwardsPoint::new(zero, -one, a, d);
+        }
+        if v == zero || u == -one {
+            return Err(ECError::NoEdwardsImage(u.value(), v.value()));
+        }
+
+        EdwardsPoint::new(u / v, (u - one) / (u + one), a, d)
+    }
+
+    pub fn to_weierstrass(&self) -> ECPoint {
+        self.to_montgomery().to_weierstrass()
+    }
+}
+
+impl Add for EdwardsPoint {
+    type Output = Self;
+
+    // Unified addition in extended coordinates (Hisil, Wong, Carter, Dawson
+    // 2008). The same formula is used for doubling and for the identity.
+    fn add(self, other: Self) -> Self {
+        if self.a != other.a || self.d != other.d {
+            panic!("Points {}, {} are not on the same curve", self, other);
+        }
+
+        let a = self.x * other.x;
+        let b = self.y * other.y;
+        let c = self.d * self.t * other.t;
+        let d = self.z * other.z;
+        let e = (self.x + self.y) * (other.x + other.y) - a - b;
+        let f = d - c;
+        let g = d + c;
+        let h = b - self.a * a;
+
+        EdwardsPoint {
+            x: e * f,
+            y: g * h,
+            z: f * g,
+            t: e * h,
+            a: self.a,
+            d: self.d,
+        }
+    }
+}
+
+impl AddAssign for EdwardsPoint {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Neg for EdwardsPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        EdwardsPoint {
+            x: -self.x,
+            t: -self.t,
+            ..self
+        }
+    }
+}
+
+impl Mul<u64> for EdwardsPoint {
+    type Output = Self;
+
+    fn mul(self, other: u64) -> Self {
+        let mut result = EdwardsPoint::identity(self.a, self.d);
+        let mut current = self;
+        let mut i = other;
+
+        while i > 0 {
+            if i % 2 == 1 {
+                result += current;
+            }
+            i >>= 1;
+            current += current;
+        }
+
+        result
+    }
+}
+
+impl Mul<EdwardsPoint> for u64 {
+    type Output = EdwardsPoint;
+
+    fn mul(self, other: EdwardsPoint) -> EdwardsPoint {
+        other * self
+    }
+}
+
+// Points are equal when their affine coordinates are equal
+impl PartialEq for EdwardsPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a
+            && self.d == other.d
+            && self.x * other.z == other.x * self.z
+            && self.y * other.z == other.y * self.z
+    }
+}
+
+impl Eq for EdwardsPoint {}
+
+impl Hash for EdwardsPoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x().hash(state);
+        self.y().hash(state);
+        self.a.hash(state);
+        self.d.hash(state);
+    }
+}
+
+impl Display for EdwardsPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x().value(), self.y().value())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // -x^2 + y^2 = 1 + 2x^2y^2 (mod 101), 108 points. -1 is a square and 2
+    // is not, so the addition law is complete.
+    fn curve() -> (Felt, Felt) {
+        (-Felt::new(1, 101), Felt::new(2, 101))
+    }
+
+    fn point(x: u64, y: u64) -> EdwardsPoint {
+        let (a, d) = curve();
+        EdwardsPoint::new(Felt::new(x, 101), Felt::new(y, 101), a, d).unwrap()
+    }
+
+    // Naive affine addition law, used as a reference
+    fn affine_add(p: EdwardsPoint, q: EdwardsPoint) -> (Felt, Felt) {
+        let one = Felt::new(1, 101);
+        let (x1, y1, x2, y2) = (p.x(), p.y(), q.x(), q.y());
+        let dxy = p.d() * x1 * x2 * y1 * y2;
+        let x3 = (x1 * y2 + y1 * x2) / (one + dxy);
+        let y3 = (y1 * y2 - p.a() * x1 * x2) / (one - dxy);
+        (x3, y3)
+    }
+
+    fn all_points() -> Vec<EdwardsPoint> {
+        let (a, d) = curve();
+        let mut points = Vec::new();
+        for x in 0..101 {
+            for y in 0..101 {
+                if let Ok(p) = EdwardsPoint::new(Felt::new(x, 101), Felt::new(y, 101), a, d) {
+                    points.push(p);
+                }
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn test_point_on_curve() {
+        let (a, d) = curve();
+        let p = EdwardsPoint::new(Felt::new(4, 101), Felt::new(25, 101), a, d);
+        assert!(p.is_ok());
+    }
+
+    #[test]
+    fn test_point_not_on_curve() {
+        let (a, d) = curve();
+        let p = EdwardsPoint::new(Felt::new(4, 101), Felt::new(24, 101), a, d);
+        assert!(p.is_err());
+    }
+
+    #[test]
+    fn test_singular_curve() {
+        let a = Felt::new(2, 101);
+        let p = EdwardsPoint::new(Felt::new(0, 101), Felt::new(1, 101), a, a);
+        assert!(matches!(p, Err(ECError::SingularEdwardsCurve(2, 2))));
+    }
+
+    #[test]
+    fn test_has_complete_addition() {
+        let (a, d) = curve();
+        assert!(EdwardsPoint::has_complete_addition(a, d));
+        assert!(!EdwardsPoint::has_complete_addition(a, Felt::new(4, 101)));
+    }
+
+    #[test]
+    fn test_addition_is_complete() {
+        let points = all_points();
+        assert_eq!(points.len(), 108);
+
+        for &p in points.iter() {
+            for &q in points.iter() {
+                let (x, y) = affine_add(p, q);
+                let sum = p + q;
+                assert_eq!((sum.x(), sum.y()), (x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_identity() {
+        let (a, d) = curve();
+        let p = point(4, 25);
+        let identity = EdwardsPoint::identity(a, d);
+        assert_eq!(p + identity, p);
+        assert_eq!(identity + identity, identity);
+        assert!((p + (-p)).is_identity());
+    }
+
+    #[test]
+    fn test_compare_multiplication_and_addition() {
+        let (a, d) = curve();
+        let p = point(13, 46);
+
+        let mut p_add = EdwardsPoint::identity(a, d);
+        for i in 1..200 {
+            p_add += p;
+            assert_eq!(p_add, i * p);
+        }
+    }
+
+    #[test]
+    fn test_to_montgomery_preserves_addition() {
+        let p = point(4, 25);
+        let q = point(13, 46);
+
+        assert_eq!(
+            (p + q).to_montgomery(),
+            p.to_montgomery() + q.to_montgomery()
+        );
+        assert_eq!(
+            (p + p).to_montgomery(),
+            p.to_montgomery() + p.to_montgomery()
+        );
+        assert!((p + (-p)).to_montgomery().is_infinity());
+    }
+
+    #[test]
+    fn test_montgomery_round_trip() {
+        let (a, d) = curve();
+        for p in all_points() {
+            let m = p.to_montgomery();
+            assert_eq!(EdwardsPoint::from_montgomery(m, a, d).unwrap(), p);
+        }
+    }
+
+    #[test]
+    fn test_from_montgomery_curve_mismatch() {
+        let (a, _) = curve();
+        let m = point(4, 25).to_montgomery();
+        let result = EdwardsPoint::from_montgomery(m, a, Felt::new(3, 101));
+        assert!(matches!(result, Err(ECError::CurveMismatch(_, _))));
+    }
+
+    #[test]
+    fn test_to_weierstrass_preserves_addition() {
+        let p = point(4, 25);
+        let q = point(14, 16);
+
+        assert_eq!(
+            (p + q).to_weierstrass(),
+            p.to_weierstrass() + q.to_weierstrass()
+        );
+        assert_eq!((55 * p).to_weierstrass(), 55 * p.to_weierstrass());
+    }
+
+    #[test]
+    fn test_equality_is_projective() {
+        let p = point(4, 25);
+        let doubled = p + p;
+        let q = EdwardsPoint::new(doubled.x(), doubled.y(), p.a(), p.d()).unwrap();
+        assert_eq!(doubled, q);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", point(4, 25)), "(4, 25)");
+    }
+}
@@ -0,0 +1,121 @@
+use super::ec_point::ECPoint;
+
+// Precomputed multiples of a fixed point for repeated g * k. Row i holds
+// j * 2^(wi) * g for j in 1..2^w, so g * k is the sum of one entry per
+// w-bit window of k and needs no doublings. A u64 scalar takes ceil(64 / w)
+// additions and the table stores ceil(64 / w) * (2^w - 1) points.
+#[derive(Debug, Clone)]
+pub struct FixedBaseTable {
+    generator: ECPoint,
+    window: usize,
+    rows: Vec<Vec<ECPoint>>,
+}
+
+impl FixedBaseTable {
+    pub fn new(generator: ECPoint, window: usize) -> Self {
+        assert!(
+            (1..=12).contains(&window),
+            "Window width must be between 1 and 12"
+        );
+
+        let row_count = 64_usize.div_ceil(window);
+        let mut rows = Vec::with_capacity(row_count);
+        let mut base = generator;
+        for _ in 0..row_count {
+            let mut row = Vec::with_capacity((1 << window) - 1);
+            let mut multiple = base;
+            for _ in 1..1 << window {
+                row.push(multiple);
+                multiple += base;
+            }
+            // multiple = 2^w * base
+            base = multiple;
+            rows.push(row);
+        }
+
+        FixedBaseTable {
+            generator,
+            window,
+            rows,
+        }
+    }
+
+    pub fn generator(&self) -> ECPoint {
+        self.generator
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    // Number of precomputed points
+    pub fn len(&self) -> usize {
+        self.rows.iter().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn mul(&self, k: u64) -> ECPoint {
+        let mask = (1_u64 << self.window) - 1;
+        let mut result = ECPoint::infinity(self.generator.a(), self.generator.b());
+
+        for (i, row) in self.rows.iter().enumerate() {
+            let digit = (k >> (i * self.window)) & mask;
+            if digit != 0 {
+                result += row[digit as usize - 1];
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::felt::felt::Felt;
+
+    fn generator() -> ECPoint {
+        let modulus = 1021;
+        let a = -Felt::new(3, modulus);
+        let b = -Felt::new(3, modulus);
+        ECPoint::new(Felt::new(379, modulus), Felt::new(1011, modulus), a, b).unwrap()
+    }
+
+    #[test]
+    fn test_table_size() {
+        assert_eq!(FixedBaseTable::new(generator(), 1).len(), 64);
+        assert_eq!(FixedBaseTable::new(generator(), 4).len(), 16 * 15);
+        // 64 = 5 * 12 + 4, the last row covers only 4 bits but is full size
+        assert_eq!(FixedBaseTable::new(generator(), 12).len(), 6 * 4095);
+    }
+
+    #[test]
+    fn test_mul_matches_double_and_add() {
+        let g = generator();
+        for window in [1, 3, 4, 5, 8] {
+            let table = FixedBaseTable::new(g, window);
+            for k in [0, 1, 2, 15, 16, 655, 1038, 1039, u64::MAX] {
+                assert_eq!(table.mul(k), g * k, "k = {}, window = {}", k, window);
+            }
+        }
+    }
+
+    #[test]
+    fn test_diffie_hellman_keys() {
+        let a = Felt::new(0, 43);
+        let b = Felt::new(6, 43);
+        let g = ECPoint::new(Felt::new(13, 43), Felt::new(15, 43), a, b).unwrap();
+        let table = FixedBaseTable::new(g, 4);
+        assert_eq!(table.mul(7).to_string(), "(27, 9)");
+        assert_eq!(table.mul(11).to_string(), "(33, 9)");
+    }
+
+    #[test]
+    #[should_panic(expected = "Window width must be between 1 and 12")]
+    fn test_window_too_large_should_panic() {
+        FixedBaseTable::new(generator(), 13);
+    }
+}
@@ -0,0 +1,239 @@
+use crate::felt::{felt::Felt, primes::is_prime};
+
+use super::ec_point::ECPoint;
+
+// GLV endomorphism phi(x, y) = (beta x, y) of a curve y^2 = x^3 + b (j = 0)
+// with p = 1 (mod 3), where beta is a cube root of unity mod p. On a subgroup
+// of prime order n it acts as multiplication by a cube root of unity lambda
+// mod n, so k P = k1 P + k2 phi(P) with k1, k2 about sqrt(n) in size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlvEndomorphism {
+    beta: Felt,
+    lambda: u64,
+    order: u64,
+    // Short basis of the lattice {(x, y) : x + y lambda = 0 (mod n)}
+    v1: (i128, i128),
+    v2: (i128, i128),
+}
+
+impl GlvEndomorphism {
+    pub fn is_supported_curve(a: Felt, b: Felt) -> bool {
+        a.value() == 0 && b.value() != 0 && a.modulus() % 3 == 1
+    }
+
+    // None unless the curve has j = 0, p = 1 (mod 3) and point has prime
+    // order n = 1 (mod 3)
+    pub fn new(point: &ECPoint, order: u64) -> Option<Self> {
+        let (a, b) = (point.a(), point.b());
+        if !GlvEndomorphism::is_supported_curve(a, b)
+            || point.is_infinity()
+            || order % 3 != 1
+            || !is_prime(order)
+        {
+            return None;
+        }
+
+        // beta = g^((p - 1) / 3) for any g that is not a cube
+        let modulus = a.modulus();
+        let one = Felt::new(1, modulus);
+        let mut g = Felt::new(2, modulus);
+        let beta = loop {
+            let root = g.pow((modulus - 1) / 3);
+            if root != one {
+                break root;
+            }
+            g = g + one;
+        };
+
+        // The cube roots of unity mod n are (-1 +- sqrt(-3)) / 2, pick the
+        // one matching this beta
+        let image = ECPoint::new(beta * point.x(), point.y(), a, b).unwrap();
+        let sqrt_minus_3 = (-Felt::new(3, order)).sqrt()?;
+        let (one_n, two_n) = (Felt::new(1, order), Felt::new(2, order));
+        let lambda = [
+            (sqrt_minus_3 - one_n) / two_n,
+            (-sqrt_minus_3 - one_n) / two_n,
+        ]
+        .into_iter()
+        .find(|lambda| *point * lambda.value() == image)?
+        .value();
+
+        let (v1, v2) = reduced_basis(order, lambda);
+        Some(GlvEndomorphism {
+            beta,
+            lambda,
+            order,
+            v1,
+            v2,
+        })
+    }
+
+    // Computes the order of point by brute force
+    pub fn for_point(point: &ECPoint) -> Option<Self> {
+        GlvEndomorphism::new(point, point.order())
+    }
+
+    pub fn beta(&self) -> Felt {
+        self.beta
+    }
+
+    pub fn lambda(&self) -> u64 {
+        self.lambda
+    }
+
+    pub fn order(&self) -> u64 {
+        self.order
+    }
+
+    pub fn apply(&self, point: &ECPoint) -> ECPoint {
+        if point.is_infinity() {
+            return *point;
+        }
+        ECPoint::new(self.beta * point.x(), point.y(), point.a(), point.b()).unwrap()
+    }
+
+    // k = k1 + k2 lambda (mod n) with |k1|, |k2| = O(sqrt(n)), by rounding
+    // (k, 0) to a nearby lattice vector
+    pub fn decompose(&self, k: u64) -> (i128, i128) {
+        let n = self.order as i128;
+        let k = (k % self.order) as i128;
+        let ((a1, b1), (a2, b2)) = (self.v1, self.v2);
+
+        let c1 = round_div(b2 * k, n);
+        let c2 = round_div(-b1 * k, n);
+        (k - c1 * a1 - c2 * a2, -c1 * b1 - c2 * b2)
+    }
+
+    // k * point for a point in the subgroup of order n, as k1 P + k2 phi(P)
+    // with Straus' method so both halves share their doublings
+    pub fn mul(&self, point: &ECPoint, k: u64) -> ECPoint {
+        let (k1, k2) = self.decompose(k);
+        let signed = |k: i128, p: ECPoint| {
+            if k < 0 {
+                (k.unsigned_abs() as u64, -p)
+            } else {
+                (k as u64, p)
+            }
+        };
+        ECPoint::straus(&[signed(k1, *point), signed(k2, self.apply(point))])
+    }
+}
+
+// round(a / n) for n > 0
+fn round_div(a: i128, n: i128) -> i128 {
+    (2 * a + n).div_euclid(2 * n)
+}
+
+// Extended Euclid on (n, lambda) gives r_i = s_i n + t_i lambda, so every
+// (r_i, -t_i) is in the lattice. Stopping where r_i drops below sqrt(n)
+// yields two short independent vectors (Gallant, Lambert and Vanstone).
+fn reduced_basis(n: u64, lambda: u64) -> ((i128, i128), (i128, i128)) {
+    let sqrt_n = (n as f64).sqrt() as i128;
+    let (mut r0, mut r1) = (n as i128, lambda as i128);
+    let (mut t0, mut t1) = (0_i128, 1_i128);
+
+    while r1 >= sqrt_n {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+
+    let q = r0 / r1;
+    let (r2, t2) = (r0 - q * r1, t0 - q * t1);
+    let v1 = (r1, -t1);
+    let v2 = if r0 * r0 + t0 * t0 <= r2 * r2 + t2 * t2 {
+        (r0, -t0)
+    } else {
+        (r2, -t2)
+    };
+    (v1, v2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // y^2 = x^3 + 2 mod 100003 has prime order 99667
+    fn generator() -> ECPoint {
+        let modulus = 100003;
+        let a = Felt::new(0, modulus);
+        let b = Felt::new(2, modulus);
+        let x = Felt::new(2, modulus);
+        let y = (x.pow(3) + b).sqrt().unwrap();
+        ECPoint::new(x, y, a, b).unwrap()
+    }
+
+    #[test]
+    fn test_supported_curves() {
+        assert!(GlvEndomorphism::is_supported_curve(
+            Felt::new(0, 43),
+            Felt::new(6, 43)
+        ));
+        // p = 2 (mod 3)
+        assert!(!GlvEndomorphism::is_supported_curve(
+            Felt::new(0, 41),
+            Felt::new(6, 41)
+        ));
+        // j != 0
+        assert!(!GlvEndomorphism::is_supported_curve(
+            -Felt::new(3, 1021),
+            -Felt::new(3, 1021)
+        ));
+    }
+
+    #[test]
+    fn test_endomorphism_is_multiplication_by_lambda() {
+        let g = generator();
+        let glv = GlvEndomorphism::new(&g, 99667).unwrap();
+        assert_eq!(glv.beta().pow(3), Felt::new(1, 100003));
+        assert_ne!(glv.beta(), Felt::new(1, 100003));
+        assert_eq!(
+            (glv.lambda() as u128 * glv.lambda() as u128 + glv.lambda() as u128 + 1) % 99667,
+            0
+        );
+        for k in [1, 2, 12345] {
+            let p = g * k;
+            assert_eq!(glv.apply(&p), p * glv.lambda());
+        }
+    }
+
+    #[test]
+    fn test_decomposition() {
+        let glv = GlvEndomorphism::new(&generator(), 99667).unwrap();
+        let n = 99667_i128;
+        let bound = 2 * (n as f64).sqrt() as i128;
+        for k in [0, 1, 2, 1000, 50000, 99666, 99667, u64::MAX] {
+            let (k1, k2) = glv.decompose(k);
+            assert_eq!(
+                (k1 + k2 * glv.lambda() as i128).rem_euclid(n),
+                k as i128 % n
+            );
+            assert!(k1.abs() <= bound && k2.abs() <= bound, "{} {}", k1, k2);
+        }
+    }
+
+    #[test]
+    fn test_mul_matches_double_and_add() {
+        let g = generator();
+        let glv = GlvEndomorphism::for_point(&g).unwrap();
+        for k in [0, 1, 2, 3, 655, 99666, 99667, 99668, 1 << 40, u64::MAX] {
+            assert_eq!(glv.mul(&g, k), g * k, "k = {}", k);
+        }
+    }
+
+    #[test]
+    fn test_diffie_hellman_curve() {
+        let a = Felt::new(0, 43);
+        let b = Felt::new(6, 43);
+        // (13, 15) has prime order 13 = 1 (mod 3)
+        let g = ECPoint::new(Felt::new(13, 43), Felt::new(15, 43), a, b).unwrap();
+        let glv = GlvEndomorphism::for_point(&g).unwrap();
+        for k in 0..30 {
+            assert_eq!(glv.mul(&g, k), g * k, "k = {}", k);
+        }
+
+        // (9, 2) has order 39, which is not prime
+        let g = ECPoint::new(Felt::new(9, 43), Felt::new(2, 43), a, b).unwrap();
+        assert!(GlvEndomorphism::for_point(&g).is_none());
+    }
+}
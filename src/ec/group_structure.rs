@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+
+use crate::felt::{
+    felt::Felt,
+    primes::{divisors, prime_factors},
+};
+
+use super::ec_point::ECPoint;
+
+// E(F_p) is isomorphic to Z/n1 x Z/n2 with n1 | n2 (and n1 | p - 1), so it is
+// cyclic exactly when n1 = 1. Every point is a P1 + b P2 for the generators
+// P1 of order n1 and P2 of order n2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupStructure {
+    n1: u64,
+    n2: u64,
+    generators: (ECPoint, ECPoint),
+}
+
+impl GroupStructure {
+    // Found with order arguments over all points, so only for small fields:
+    // n2 is the largest point order, P2 is a point of that order and P1 one
+    // of order n1 whose multiples meet <P2> only in infinity
+    pub fn compute(a: Felt, b: Felt) -> Self {
+        let points = points(a, b);
+        let order = points.len() as u64;
+        let factors = prime_factors(order);
+        let infinity = ECPoint::infinity(a, b);
+
+        let (p2, n2) = points
+            .iter()
+            .map(|&p| (p, p.order_from_factors(&factors)))
+            .fold((infinity, 1), |best, candidate| {
+                if candidate.1 > best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            });
+        let n1 = order / n2;
+
+        let mut subgroup = HashSet::with_capacity(n2 as usize);
+        let mut multiple = infinity;
+        for _ in 0..n2 {
+            subgroup.insert(multiple);
+            multiple += p2;
+        }
+
+        let p1 = points
+            .iter()
+            .copied()
+            .find(|&p| {
+                let mut multiple = p;
+                for _ in 1..n1 {
+                    if subgroup.contains(&multiple) {
+                        return false;
+                    }
+                    multiple += p;
+                }
+                multiple.is_infinity()
+            })
+            .expect("Group has a complement of order n1");
+
+        GroupStructure {
+            n1,
+            n2,
+            generators: (p1, p2),
+        }
+    }
+
+    // (n1, n2) with n1 | n2
+    pub fn invariants(&self) -> (u64, u64) {
+        (self.n1, self.n2)
+    }
+
+    // Number of points, n1 * n2
+    pub fn order(&self) -> u64 {
+        self.n1 * self.n2
+    }
+
+    // Largest order of a point, n2
+    pub fn exponent(&self) -> u64 {
+        self.n2
+    }
+
+    pub fn is_cyclic(&self) -> bool {
+        self.n1 == 1
+    }
+
+    // (P1, P2) of orders n1 and n2, P1 is infinity for a cyclic group
+    pub fn generators(&self) -> (ECPoint, ECPoint) {
+        self.generators
+    }
+
+    // A finite abelian group has subgroups of every order dividing its size
+    pub fn subgroup_orders(&self) -> Vec<u64> {
+        divisors(self.order())
+    }
+
+    // Orders of single points, i.e. of the cyclic subgroups: divisors of n2
+    pub fn point_orders(&self) -> Vec<u64> {
+        divisors(self.n2)
+    }
+
+    // A point of order d generating a cyclic subgroup, or None if d does not
+    // divide n2
+    pub fn point_of_order(&self, d: u64) -> Option<ECPoint> {
+        if d == 0 || !self.n2.is_multiple_of(d) {
+            return None;
+        }
+        Some(self.generators.1 * (self.n2 / d))
+    }
+}
+
+// All points including infinity, two square roots per x
+fn points(a: Felt, b: Felt) -> Vec<ECPoint> {
+    let modulus = a.modulus();
+    let mut points = vec![ECPoint::infinity(a, b)];
+    for x in 0..modulus {
+        let x = Felt::new(x, modulus);
+        if let Some(y) = (x.pow(3) + a * x + b).sqrt() {
+            points.push(ECPoint::new(x, y, a, b).unwrap());
+            if y.value() != 0 {
+                points.push(ECPoint::new(x, -y, a, b).unwrap());
+            }
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check_generators(group: &GroupStructure) {
+        let (n1, n2) = group.invariants();
+        let (p1, p2) = group.generators();
+        assert_eq!(p1.order(), n1);
+        assert_eq!(p2.order(), n2);
+
+        // a P1 + b P2 covers the whole group exactly once
+        let mut sums = HashSet::new();
+        for i in 0..n1 {
+            for j in 0..n2 {
+                sums.insert(p1 * i + p2 * j);
+            }
+        }
+        assert_eq!(sums.len() as u64, group.order());
+    }
+
+    #[test]
+    fn test_cyclic_group() {
+        // Group of the curve in main.rs
+        let a = Felt::new(0, 43);
+        let b = Felt::new(6, 43);
+        let group = GroupStructure::compute(a, b);
+        assert_eq!(group.invariants(), (1, 39));
+        assert!(group.is_cyclic());
+        assert!(group.generators().0.is_infinity());
+        assert_eq!(group.subgroup_orders(), vec![1, 3, 13, 39]);
+        assert_eq!(group.point_orders(), vec![1, 3, 13, 39]);
+        check_generators(&group);
+    }
+
+    #[test]
+    fn test_non_cyclic_groups() {
+        // y^2 = x^3 - x has full 2-torsion (0, 0), (1, 0) and (-1, 0)
+        let a = -Felt::new(1, 61);
+        let b = Felt::new(0, 61);
+        let group = GroupStructure::compute(a, b);
+        assert_eq!(group.invariants(), (6, 12));
+        assert!(!group.is_cyclic());
+        assert_eq!(group.order(), ECPoint::count_points(a, b));
+        assert_eq!(group.exponent(), 12);
+        assert_eq!(group.subgroup_orders().len(), 12);
+        assert_eq!(group.point_orders(), vec![1, 2, 3, 4, 6, 12]);
+        check_generators(&group);
+
+        for (modulus, invariants) in [(7, (2, 6)), (31, (6, 6))] {
+            let a = Felt::new(0, modulus);
+            let b = Felt::new(1, modulus);
+            let group = GroupStructure::compute(a, b);
+            assert_eq!(group.invariants(), invariants);
+            assert_eq!((modulus - 1) % invariants.0, 0);
+            check_generators(&group);
+        }
+    }
+
+    #[test]
+    fn test_prime_order_group() {
+        let a = -Felt::new(3, 1021);
+        let b = -Felt::new(3, 1021);
+        let group = GroupStructure::compute(a, b);
+        assert_eq!(group.invariants(), (1, 1039));
+        assert_eq!(group.generators().1.order(), 1039);
+    }
+
+    #[test]
+    fn test_point_of_order() {
+        let a = Felt::new(0, 43);
+        let b = Felt::new(6, 43);
+        let group = GroupStructure::compute(a, b);
+        for d in group.point_orders() {
+            assert_eq!(group.point_of_order(d).unwrap().order(), d);
+        }
+        assert_eq!(group.point_of_order(5), None);
+        assert_eq!(group.point_of_order(0), None);
+    }
+}
@@ -1,2 +1,23 @@
+pub mod big_ec_point;
+pub mod cm;
+pub mod curve_generator;
+pub mod division_polynomial;
 pub mod ec_errors;
 pub mod ec_point;
+pub mod ed25519;
+pub mod edwards_point;
+pub mod fixed_base;
+pub mod glv;
+pub mod group_structure;
+pub mod montgomery_point;
+pub mod named_curves;
+pub mod optimal_ate;
+pub mod pairing;
+pub mod scalar;
+pub mod scalar_mul;
+pub mod security;
+pub mod subgroup;
+pub mod twist_point;
+pub mod weierstrass_curve;
+pub mod weierstrass_point;
+pub mod x25519;
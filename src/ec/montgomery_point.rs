@@ -0,0 +1,466 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg},
+};
+
+use crate::felt::felt::Felt;
+
+use super::{ec_errors::ECError, ec_point::ECPoint};
+
+// Point on the Montgomery curve By^2 = x^3 + Ax^2 + x
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MontgomeryPoint {
+    x: Felt,
+    y: Felt,
+    a: Felt,
+    b: Felt,
+    infinity: bool,
+}
+
+impl MontgomeryPoint {
+    pub fn new(x: Felt, y: Felt, a: Felt, b: Felt) -> Result<Self, ECError> {
+        Self::verify_curve(a, b)?;
+        let point = MontgomeryPoint {
+            x,
+            y,
+            a,
+            b,
+            infinity: false,
+        };
+        point.verify_point()?;
+        Ok(point)
+    }
+
+    // B(A^2 - 4) != 0
+    fn verify_curve(a: Felt, b: Felt) -> Result<(), ECError> {
+        let felt_4 = Felt::new(4, a.modulus());
+        if b.value() == 0 || a.pow(2) == felt_4 {
+            return Err(ECError::SingularMontgomeryCurve(a.value(), b.value()));
+        }
+        Ok(())
+    }
+
+    fn verify_point(&self) -> Result<(), ECError> {
+        let lhs = self.b * self.y.pow(2);
+        let rhs = self.x.pow(3) + self.a * self.x.pow(2) + self.x;
+
+        if lhs == rhs {
+            Ok(())
+        } else {
+            Err(ECError::PointNotOnMontgomeryCurve(
+                self.x.value(),
+                self.y.value(),
+                self.a.value(),
+                self.b.value(),
+            ))
+        }
+    }
+
+    pub fn infinity(a: Felt, b: Felt) -> MontgomeryPoint {
+        MontgomeryPoint {
+            x: Felt::new(0, a.modulus()),
+            y: Felt::new(0, a.modulus()),
+            a,
+            b,
+            infinity: true,
+        }
+    }
+
+    pub fn x(&self) -> Felt {
+        self.x
+    }
+
+    pub fn y(&self) -> Felt {
+        self.y
+    }
+
+    pub fn a(&self) -> Felt {
+        self.a
+    }
+
+    pub fn b(&self) -> Felt {
+        self.b
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.infinity
+    }
+
+    // Short Weierstrass coefficients (a, b) of the curve birationally
+    // equivalent to By^2 = x^3 + Ax^2 + x:
+    // a = (3 - A^2) / (3B^2), b = (2A^3 - 9A) / (27B^3)
+    pub fn weierstrass_coefficients(a: Felt, b: Felt) -> (Felt, Felt) {
+        let modulus = a.modulus();
+        let felt_2 = Felt::new(2, modulus);
+        let felt_3 = Felt::new(3, modulus);
+        let felt_9 = Felt::new(9, modulus);
+        let felt_27 = Felt::new(27, modulus);
+
+        let weierstrass_a = (felt_3 - a.pow(2)) / (felt_3 * b.pow(2));
+        let weierstrass_b = (felt_2 * a.pow(3) - felt_9 * a) / (felt_27 * b.pow(3));
+
+        (weierstrass_a, weierstrass_b)
+    }
+
+    // Montgomery coefficients (A, B) for the curve y^2 = x^3 + ax + b.
+    // A Montgomery form exists iff x^3 + ax + b has a root alpha such that
+    // 3alpha^2 + a is a square, then B = 1/sqrt(3alpha^2 + a), A = 3alpha*B.
+    // The modulus must be a prime greater than 3.
+    pub fn montgomery_coefficients(a: Felt, b: Felt) -> Result<(Felt, Felt), ECError> {
+        let modulus = a.modulus();
+        let felt_3 = Felt::new(3, modulus);
+
+        // Naive search for the roots of x^3 + ax + b
+        for alpha in 0..modulus {
+            let alpha = Felt::new(alpha, modulus);
+            if (alpha.pow(3) + a * alpha + b).value() != 0 {
+                continue;
+            }
+            if let Some(root) = (felt_3 * alpha.pow(2) + a).sqrt() {
+                if let Ok(s) = root.inverse() {
+                    return Ok((felt_3 * alpha * s, s));
+                }
+            }
+        }
+
+        Err(ECError::NoMontgomeryForm(a.value(), b.value()))
+    }
+
+    // (x, y) -> ((x + A/3) / B, y / B)
+    pub fn to_weierstrass(&self) -> ECPoint {
+        let (weierstrass_a, weierstrass_b) = Self::weierstrass_coefficients(self.a, self.b);
+        if self.infinity {
+            return ECPoint::infinity(weierstrass_a, weierstrass_b);
+        }
+
+        let felt_3 = Felt::new(3, self.a.modulus());
+        let x = (self.x + self.a / felt_3) / self.b;
+        let y = self.y / self.b;
+
+        ECPoint::new(x, y, weierstrass_a, weierstrass_b).unwrap()
+    }
+
+    // Inverse of to_weierstrass onto the Montgomery curve with coefficients
+    // (a, b): (x, y) -> (Bx - A/3, By)
+    pub fn from_weierstrass(point: ECPoint, a: Felt, b: Felt) -> Result<Self, ECError> {
+        Self::verify_curve(a, b)?;
+        let (weierstrass_a, weierstrass_b) = Self::weierstrass_coefficients(a, b);
+        if (point.a(), point.b()) != (weierstrass_a, weierstrass_b) {
+            return Err(ECError::CurveMismatch(
+                format!("y^2 = x^3 + {}x + {}", point.a().value(), point.b().value()),
+                format!(
+                    "y^2 = x^3 + {}x + {}",
+                    weierstrass_a.value(),
+                    weierstrass_b.value()
+                ),
+            ));
+        }
+        if point.is_infinity() {
+            return Ok(MontgomeryPoint::infinity(a, b));
+        }
+
+        let felt_3 = Felt::new(3, a.modulus());
+        let x = b * point.x() - a / felt_3;
+        let y = b * point.y();
+
+        MontgomeryPoint::new(x, y, a, b)
+    }
+
+    // Montgomery ladder on x-coordinates only. Returns None when k*self is
+    // the point at infinity.
+    pub fn ladder_x(&self, k: u64) -> Option<Felt> {
+        if self.infinity {
+            return None;
+        }
+
+        let modulus = self.a.modulus();
+        let zero = Felt::new(0, modulus);
+        let one = Felt::new(1, modulus);
+
+        // (0, 0) has order 2 and breaks the differential addition
+        if self.x == zero {
+            return if k.is_multiple_of(2) {
+                None
+            } else {
+                Some(zero)
+            };
+        }
+
+        let mut r0 = (one, zero);
+        let mut r1 = (self.x, one);
+        for i in (0..u64::BITS).rev() {
+            if (k >> i) & 1 == 0 {
+                r1 = self.x_add(r0, r1);
+                r0 = self.x_double(r0);
+            } else {
+                r0 = self.x_add(r0, r1);
+                r1 = self.x_double(r1);
+            }
+        }
+
+        let (x, z) = r0;
+        if z == zero {
+            None
+        } else {
+            Some(x / z)
+        }
+    }
+
+    // X2 = (X + Z)^2 (X - Z)^2, Z2 = 4XZ((X - Z)^2 + (A + 2)/4 * 4XZ)
+    fn x_double(&self, (x, z): (Felt, Felt)) -> (Felt, Felt) {
+        let modulus = self.a.modulus();
+        let a24 = (self.a + Felt::new(2, modulus)) / Felt::new(4, modulus);
+
+        let sum = (x + z).pow(2);
+        let diff = (x - z).pow(2);
+        let xz4 = sum - diff;
+
+        (sum * diff, xz4 * (diff + a24 * xz4))
+    }
+
+    // Differential addition of P and Q, where Q - P = self
+    fn x_add(&self, (xp, zp): (Felt, Felt), (xq, zq): (Felt, Felt)) -> (Felt, Felt) {
+        let da = (xq - zq) * (xp + zp);
+        let cb = (xq + zq) * (xp - zp);
+
+        ((da + cb).pow(2), self.x * (da - cb).pow(2))
+    }
+}
+
+impl Add for MontgomeryPoint {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.a != other.a || self.b != other.b {
+            panic!("Points {}, {} are not on the same curve", self, other);
+        }
+
+        // P + 0 = P
+        if self.infinity {
+            return other;
+        }
+        if other.infinity {
+            return self;
+        }
+
+        // P + (-P) = 0
+        if self == -other {
+            return MontgomeryPoint::infinity(self.a, self.b);
+        }
+
+        let s = if self == other {
+            let felt_1 = Felt::new(1, self.a.modulus());
+            let felt_2 = Felt::new(2, self.a.modulus());
+            let felt_3 = Felt::new(3, self.a.modulus());
+
+            (felt_3 * self.x.pow(2) + felt_2 * self.a * self.x + felt_1)
+                / (felt_2 * self.b * self.y)
+        } else {
+            (other.y - self.y) / (other.x - self.x)
+        };
+
+        let x = self.b * s.pow(2) - self.a - self.x - other.x;
+        let y = s * (self.x - x) - self.y;
+
+        MontgomeryPoint::new(x, y, self.a, self.b).unwrap()
+    }
+}
+
+impl AddAssign for MontgomeryPoint {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Neg for MontgomeryPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.infinity {
+            return self;
+        }
+        MontgomeryPoint::new(self.x, -self.y, self.a, self.b).unwrap()
+    }
+}
+
+impl Mul<u64> for MontgomeryPoint {
+    type Output = Self;
+
+    fn mul(self, other: u64) -> Self {
+        let mut result = MontgomeryPoint::infinity(self.a, self.b);
+        let mut current = self;
+        let mut i = other;
+
+        while i > 0 {
+            if i % 2 == 1 {
+                result += current;
+            }
+            i >>= 1;
+            current += current;
+        }
+
+        result
+    }
+}
+
+impl Mul<MontgomeryPoint> for u64 {
+    type Output = MontgomeryPoint;
+
+    fn mul(self, other: MontgomeryPoint) -> MontgomeryPoint {
+        other * self
+    }
+}
+
+impl Display for MontgomeryPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.infinity {
+            write!(f, "Infinity")
+        } else {
+            write!(f, "({}, {})", self.x.value(), self.y.value())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 5y^2 = x^3 + 3x^2 + x (mod 101), 96 points
+    fn curve() -> (Felt, Felt) {
+        (Felt::new(3, 101), Felt::new(5, 101))
+    }
+
+    fn point(x: u64, y: u64) -> MontgomeryPoint {
+        let (a, b) = curve();
+        MontgomeryPoint::new(Felt::new(x, 101), Felt::new(y, 101), a, b).unwrap()
+    }
+
+    #[test]
+    fn test_point_on_curve() {
+        let (a, b) = curve();
+        let point = MontgomeryPoint::new(Felt::new(2, 101), Felt::new(41, 101), a, b);
+        assert!(point.is_ok());
+    }
+
+    #[test]
+    fn test_point_not_on_curve() {
+        let (a, b) = curve();
+        let point = MontgomeryPoint::new(Felt::new(2, 101), Felt::new(40, 101), a, b);
+        assert!(point.is_err());
+    }
+
+    #[test]
+    fn test_singular_curve() {
+        let a = Felt::new(2, 101);
+        let b = Felt::new(1, 101);
+        let point = MontgomeryPoint::new(Felt::new(0, 101), Felt::new(0, 101), a, b);
+        assert!(matches!(point, Err(ECError::SingularMontgomeryCurve(2, 1))));
+    }
+
+    #[test]
+    fn test_add_point_with_its_addition_inverse() {
+        let (a, b) = curve();
+        let p = point(2, 41);
+        assert_eq!(p + (-p), MontgomeryPoint::infinity(a, b));
+    }
+
+    #[test]
+    fn test_point_of_order_two() {
+        let (a, b) = curve();
+        let p = point(0, 0);
+        assert_eq!(p + p, MontgomeryPoint::infinity(a, b));
+    }
+
+    #[test]
+    fn test_compare_multiplication_and_addition() {
+        let (a, b) = curve();
+        let p = point(2, 41);
+
+        let mut p_add = MontgomeryPoint::infinity(a, b);
+        for i in 1..200 {
+            p_add += p;
+            assert_eq!(p_add, i * p);
+        }
+    }
+
+    #[test]
+    fn test_ladder_x_matches_multiplication() {
+        for p in [point(2, 41), point(14, 12), point(1, 100), point(0, 0)] {
+            for k in 0..200 {
+                let kp = k * p;
+                let expected = if kp.is_infinity() { None } else { Some(kp.x()) };
+                assert_eq!(p.ladder_x(k), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_weierstrass_preserves_addition() {
+        let p = point(2, 41);
+        let q = point(14, 12);
+
+        assert_eq!(
+            (p + q).to_weierstrass(),
+            p.to_weierstrass() + q.to_weierstrass()
+        );
+        assert_eq!(
+            (p + p).to_weierstrass(),
+            p.to_weierstrass() + p.to_weierstrass()
+        );
+        assert_eq!((17 * p).to_weierstrass(), 17 * p.to_weierstrass());
+    }
+
+    #[test]
+    fn test_weierstrass_coefficients() {
+        let (a, b) = curve();
+        let (weierstrass_a, weierstrass_b) = MontgomeryPoint::weierstrass_coefficients(a, b);
+        assert_eq!(weierstrass_a, Felt::new(8, 101));
+        assert_eq!(weierstrass_b, Felt::new(80, 101));
+    }
+
+    #[test]
+    fn test_from_weierstrass_round_trip() {
+        let (a, b) = curve();
+        let p = point(14, 12);
+        let w = p.to_weierstrass();
+        assert_eq!(MontgomeryPoint::from_weierstrass(w, a, b).unwrap(), p);
+    }
+
+    #[test]
+    fn test_from_weierstrass_curve_mismatch() {
+        let (a, b) = curve();
+        let w = point(14, 12).to_weierstrass();
+        let result = MontgomeryPoint::from_weierstrass(w, Felt::new(4, 101), b);
+        assert!(matches!(result, Err(ECError::CurveMismatch(_, _))));
+        assert!(MontgomeryPoint::from_weierstrass(w, a, b).is_ok());
+    }
+
+    #[test]
+    fn test_montgomery_coefficients() {
+        let a = Felt::new(8, 101);
+        let b = Felt::new(80, 101);
+        let (mont_a, mont_b) = MontgomeryPoint::montgomery_coefficients(a, b).unwrap();
+
+        assert_eq!(
+            MontgomeryPoint::weierstrass_coefficients(mont_a, mont_b),
+            (a, b)
+        );
+
+        let w = point(14, 12).to_weierstrass();
+        let m = MontgomeryPoint::from_weierstrass(w, mont_a, mont_b).unwrap();
+        assert_eq!(m.to_weierstrass(), w);
+    }
+
+    #[test]
+    fn test_no_montgomery_form() {
+        // x^3 + x + 1 has no roots mod 7, so there is no point of order 2
+        let a = Felt::new(1, 7);
+        let b = Felt::new(1, 7);
+        assert!(MontgomeryPoint::montgomery_coefficients(a, b).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", point(2, 41)), "(2, 41)");
+    }
+}
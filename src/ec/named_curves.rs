@@ -0,0 +1,367 @@
+use crate::felt::{big_felt::BigFelt, big_uint::BigUint, felt::Felt};
+
+use super::{big_ec_point::BigECPoint, ec_errors::ECError, ec_point::ECPoint};
+
+// Equation the parameters a, b of a named curve refer to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveModel {
+    // y^2 = x^3 + ax + b
+    ShortWeierstrass,
+    // by^2 = x^3 + ax^2 + x
+    Montgomery,
+    // ax^2 + y^2 = 1 + bx^2y^2
+    TwistedEdwards,
+}
+
+// Standard domain parameters, stored as hex strings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NamedCurve {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    oid: Option<&'static str>,
+    model: CurveModel,
+    p: &'static str,
+    a: &'static str,
+    b: &'static str,
+    gx: &'static str,
+    gy: &'static str,
+    order: &'static str,
+    cofactor: &'static str,
+}
+
+const NAMED_CURVES: [NamedCurve; 9] = [
+    NamedCurve {
+        name: "secp256k1",
+        aliases: &[],
+        oid: Some("1.3.132.0.10"),
+        model: CurveModel::ShortWeierstrass,
+        p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        a: "0",
+        b: "7",
+        gx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        gy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        order: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+        cofactor: "1",
+    },
+    NamedCurve {
+        name: "P-256",
+        aliases: &["secp256r1", "prime256v1", "nistp256"],
+        oid: Some("1.2.840.10045.3.1.7"),
+        model: CurveModel::ShortWeierstrass,
+        p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+        b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+        gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        order: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+        cofactor: "1",
+    },
+    NamedCurve {
+        name: "P-384",
+        aliases: &["secp384r1", "nistp384"],
+        oid: Some("1.3.132.0.34"),
+        model: CurveModel::ShortWeierstrass,
+        p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+        a: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000fffffffc",
+        b: "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+        gx: "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+        gy: "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+        order: "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+        cofactor: "1",
+    },
+    NamedCurve {
+        name: "Curve25519",
+        aliases: &["X25519"],
+        oid: Some("1.3.101.110"),
+        model: CurveModel::Montgomery,
+        p: "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed",
+        a: "76d06",
+        b: "1",
+        gx: "9",
+        gy: "20ae19a1b8a086b4e01edd2c7748d14c923d4d7e6d7c61b229e9c5a27eced3d9",
+        order: "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed",
+        cofactor: "8",
+    },
+    NamedCurve {
+        name: "Ed25519",
+        aliases: &["edwards25519"],
+        oid: Some("1.3.101.112"),
+        model: CurveModel::TwistedEdwards,
+        p: "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed",
+        a: "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffec",
+        b: "52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3",
+        gx: "216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a",
+        gy: "6666666666666666666666666666666666666666666666666666666666666658",
+        order: "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed",
+        cofactor: "8",
+    },
+    NamedCurve {
+        name: "BN254",
+        aliases: &["alt_bn128", "bn128"],
+        oid: None,
+        model: CurveModel::ShortWeierstrass,
+        p: "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+        a: "0",
+        b: "3",
+        gx: "1",
+        gy: "2",
+        order: "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+        cofactor: "1",
+    },
+    NamedCurve {
+        name: "BLS12-381",
+        aliases: &[],
+        oid: None,
+        model: CurveModel::ShortWeierstrass,
+        p: "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+        a: "0",
+        b: "4",
+        gx: "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+        gy: "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1",
+        order: "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+        cofactor: "396c8c005555e1568c00aaab0000aaab",
+    },
+    // y^2 = x^3 - 3x - 3 mod 1021 from the README, the group has prime order
+    NamedCurve {
+        name: "toy-1021",
+        aliases: &[],
+        oid: None,
+        model: CurveModel::ShortWeierstrass,
+        p: "3fd",
+        a: "3fa",
+        b: "3fa",
+        gx: "17b",
+        gy: "3f3",
+        order: "40f",
+        cofactor: "1",
+    },
+    // y^2 = x^3 + 6 mod 43 with the Diffie-Hellman example's generator (13, 15).
+    // The group has 39 points, (9, 2) generates all of them.
+    NamedCurve {
+        name: "toy-43",
+        aliases: &[],
+        oid: None,
+        model: CurveModel::ShortWeierstrass,
+        p: "2b",
+        a: "0",
+        b: "6",
+        gx: "d",
+        gy: "f",
+        order: "d",
+        cofactor: "3",
+    },
+];
+
+// All curves in the registry
+pub fn named_curves() -> &'static [NamedCurve] {
+    &NAMED_CURVES
+}
+
+// Case insensitive lookup by name or alias, '-' and '_' are ignored
+pub fn named_curve(name: &str) -> Option<&'static NamedCurve> {
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(|&c| c != '-' && c != '_')
+            .collect::<String>()
+            .to_lowercase()
+    };
+    let name = normalize(name);
+    NAMED_CURVES.iter().find(|curve| {
+        normalize(curve.name) == name || curve.aliases.iter().any(|&alias| normalize(alias) == name)
+    })
+}
+
+pub fn named_curve_by_oid(oid: &str) -> Option<&'static NamedCurve> {
+    NAMED_CURVES.iter().find(|curve| curve.oid == Some(oid))
+}
+
+impl NamedCurve {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    pub fn oid(&self) -> Option<&'static str> {
+        self.oid
+    }
+
+    pub fn model(&self) -> CurveModel {
+        self.model
+    }
+
+    pub fn p(&self) -> BigUint {
+        parse(self.p)
+    }
+
+    pub fn a(&self) -> BigFelt {
+        BigFelt::new(parse(self.a), self.p())
+    }
+
+    pub fn b(&self) -> BigFelt {
+        BigFelt::new(parse(self.b), self.p())
+    }
+
+    pub fn generator(&self) -> (BigFelt, BigFelt) {
+        (
+            BigFelt::new(parse(self.gx), self.p()),
+            BigFelt::new(parse(self.gy), self.p()),
+        )
+    }
+
+    // Order of the generator
+    pub fn order(&self) -> BigUint {
+        parse(self.order)
+    }
+
+    // Number of points on the curve divided by the order
+    pub fn cofactor(&self) -> BigUint {
+        parse(self.cofactor)
+    }
+
+    // Checks the curve equation of the model
+    pub fn contains(&self, x: BigFelt, y: BigFelt) -> bool {
+        let (a, b) = (self.a(), self.b());
+        let one = BigFelt::from_u64(1, self.p());
+        match self.model {
+            CurveModel::ShortWeierstrass => y * y == x * x * x + a * x + b,
+            CurveModel::Montgomery => b * y * y == x * x * x + a * x * x + x,
+            CurveModel::TwistedEdwards => a * x * x + y * y == one + b * x * x * y * y,
+        }
+    }
+
+    // Generator on the isomorphic short Weierstrass curve, through the
+    // Montgomery form for Edwards curves
+    pub fn short_weierstrass_generator(&self) -> BigECPoint {
+        let p = self.p();
+        let felt = |value| BigFelt::from_u64(value, p);
+        let (x, y) = self.generator();
+
+        let (a, b, u, v) = match self.model {
+            CurveModel::ShortWeierstrass => {
+                return BigECPoint::new(x, y, self.a(), self.b()).unwrap();
+            }
+            CurveModel::Montgomery => (self.a(), self.b(), x, y),
+            // A = 2(a + d) / (a - d), B = 4 / (a - d), u = (1 + y) / (1 - y), v = u / x
+            CurveModel::TwistedEdwards => {
+                let (a, d) = (self.a(), self.b());
+                let u = (felt(1) + y) / (felt(1) - y);
+                (felt(2) * (a + d) / (a - d), felt(4) / (a - d), u, u / x)
+            }
+        };
+
+        // x = u/B + A/(3B), y = v/B on y^2 = x^3 + (3 - A^2)/(3B^2) x + (2A^3 - 9A)/(27B^3)
+        let short_a = (felt(3) - a * a) / (felt(3) * b * b);
+        let short_b = (felt(2) * a * a * a - felt(9) * a) / (felt(27) * b * b * b);
+        let x = u / b + a / (felt(3) * b);
+        BigECPoint::new(x, v / b, short_a, short_b).unwrap()
+    }
+
+    // Coefficients (a, b) as Felts, only for short Weierstrass curves over a
+    // 64-bit field
+    pub fn ec_coefficients(&self) -> Result<(Felt, Felt), ECError> {
+        match (self.model, self.p().to_u64()) {
+            (CurveModel::ShortWeierstrass, Some(modulus)) => Ok((
+                Felt::new(self.a().value().to_u64().unwrap(), modulus),
+                Felt::new(self.b().value().to_u64().unwrap(), modulus),
+            )),
+            _ => Err(ECError::UnsupportedNamedCurve(self.name.to_string())),
+        }
+    }
+
+    // Generator as an ECPoint, see ec_coefficients
+    pub fn ec_generator(&self) -> Result<ECPoint, ECError> {
+        let (a, b) = self.ec_coefficients()?;
+        let felt = |value: BigFelt| Felt::new(value.value().to_u64().unwrap(), a.modulus());
+        let (x, y) = self.generator();
+        ECPoint::new(felt(x), felt(y), a, b)
+    }
+}
+
+fn parse(hex: &str) -> BigUint {
+    BigUint::from_hex(hex).expect("registry constants are valid hex")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lookup_by_name() {
+        assert_eq!(named_curve("secp256k1").unwrap().name(), "secp256k1");
+        assert_eq!(named_curve("p256").unwrap().name(), "P-256");
+        assert_eq!(named_curve("PRIME256V1").unwrap().name(), "P-256");
+        assert_eq!(named_curve("alt-bn128").unwrap().name(), "BN254");
+        assert_eq!(named_curve("bls12_381").unwrap().name(), "BLS12-381");
+        assert!(named_curve("secp256k2").is_none());
+    }
+
+    #[test]
+    fn test_lookup_by_oid() {
+        assert_eq!(named_curve_by_oid("1.3.132.0.34").unwrap().name(), "P-384");
+        assert_eq!(named_curve_by_oid("1.3.101.112").unwrap().name(), "Ed25519");
+        assert!(named_curve_by_oid("1.2.3").is_none());
+    }
+
+    #[test]
+    fn test_names_are_unique() {
+        for (i, curve) in named_curves().iter().enumerate() {
+            assert_eq!(named_curve(curve.name()), Some(&named_curves()[i]));
+            for alias in curve.aliases() {
+                assert_eq!(named_curve(alias), Some(&named_curves()[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_generators_are_on_their_curves() {
+        for curve in named_curves() {
+            let (x, y) = curve.generator();
+            assert!(curve.contains(x, y), "{}", curve.name());
+            assert!(!curve.contains(x, y + BigFelt::from_u64(1, curve.p())));
+        }
+    }
+
+    #[test]
+    fn test_order_times_generator_is_infinity() {
+        for curve in named_curves() {
+            let g = curve.short_weierstrass_generator();
+            assert!((g * curve.order()).is_infinity(), "{}", curve.name());
+        }
+    }
+
+    // Both are birationally equivalent, so their Weierstrass forms share j
+    #[test]
+    fn test_curve25519_and_ed25519_have_the_same_j_invariant() {
+        let j = |curve: &NamedCurve| {
+            let g = curve.short_weierstrass_generator();
+            let p = curve.p();
+            let four_a3 = BigFelt::from_u64(4, p) * g.a() * g.a() * g.a();
+            BigFelt::from_u64(1728, p) * four_a3
+                / (four_a3 + BigFelt::from_u64(27, p) * g.b() * g.b())
+        };
+        let montgomery = named_curve("Curve25519").unwrap();
+        let edwards = named_curve("Ed25519").unwrap();
+        assert_eq!(j(montgomery), j(edwards));
+    }
+
+    #[test]
+    fn test_toy_curves_as_ec_points() {
+        for name in ["toy-1021", "toy-43"] {
+            let curve = named_curve(name).unwrap();
+            let g = curve.ec_generator().unwrap();
+            let order = curve.order().to_u64().unwrap();
+            let cofactor = curve.cofactor().to_u64().unwrap();
+            assert_eq!(g.order(), order);
+            assert_eq!(ECPoint::count_points(g.a(), g.b()), order * cofactor);
+        }
+    }
+
+    #[test]
+    fn test_big_curves_have_no_ec_point_generator() {
+        assert!(named_curve("secp256k1").unwrap().ec_generator().is_err());
+        assert!(named_curve("Ed25519").unwrap().ec_generator().is_err());
+    }
+}
@@ -0,0 +1,515 @@
+use std::sync::OnceLock;
+
+use crate::felt::{big_felt::BigFelt, big_fp2::BigFp2, big_uint::BigUint, fp12::Fp12};
+
+use super::{
+    big_ec_point::BigECPoint,
+    named_curves::{named_curve, NamedCurve},
+    twist_point::TwistPoint,
+};
+
+// Pairing friendly curves y^2 = x^3 + b over F_p with embedding degree 12.
+// G1 is the named curve's prime order subgroup, G2 lives on a sextic twist
+// over F_p^2 and the pairing takes values in F_p^12 = F_p^2[w] / (w^6 - xi).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairingCurve {
+    // x = 0x44e992b44a6909f1, p = 36x^4 + 36x^3 + 24x^2 + 6x + 1
+    Bn254,
+    // x = -0xd201000000010000, p = (x - 1)^2 (x^4 - x^2 + 1) / 3 + x
+    Bls12_381,
+}
+
+// How the twist E' embeds into E(F_p^12): (x w^2, y w^3) on E': y^2 = x^3 + b / xi
+// for a D-type twist, (x / w^2, y / w^3) on E': y^2 = x^3 + b xi for M-type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TwistType {
+    D,
+    M,
+}
+
+// xi^((p - 1) / 6) for the Frobenius of F_p^12, and the factors gamma_x,
+// gamma_y that carry it to the twist
+#[derive(Debug, Clone, Copy)]
+struct FrobeniusConstants {
+    gamma: BigFp2,
+    gamma_x: BigFp2,
+    gamma_y: BigFp2,
+}
+
+impl PairingCurve {
+    pub fn named_curve(&self) -> &'static NamedCurve {
+        match self {
+            PairingCurve::Bn254 => named_curve("BN254").unwrap(),
+            PairingCurve::Bls12_381 => named_curve("BLS12-381").unwrap(),
+        }
+    }
+
+    pub fn p(&self) -> BigUint {
+        self.named_curve().p()
+    }
+
+    // Order of G1, G2 and GT
+    pub fn r(&self) -> BigUint {
+        self.named_curve().order()
+    }
+
+    // The non-residue defining F_p^12 over F_p^2
+    pub fn xi(&self) -> BigFp2 {
+        match self {
+            PairingCurve::Bn254 => BigFp2::from_u64(9, 1, self.p()),
+            PairingCurve::Bls12_381 => BigFp2::from_u64(1, 1, self.p()),
+        }
+    }
+
+    // b' of the twist y^2 = x^3 + b'
+    pub fn twist_b(&self) -> BigFp2 {
+        let b = BigFp2::from_big_felt(self.named_curve().b());
+        match self.twist_type() {
+            TwistType::D => b / self.xi(),
+            TwistType::M => b * self.xi(),
+        }
+    }
+
+    pub fn g1(&self) -> BigECPoint {
+        self.named_curve().short_weierstrass_generator()
+    }
+
+    // The standard G2 generators from EIP-197 and the BLS12-381 draft
+    pub fn g2(&self) -> TwistPoint {
+        let coordinates = match self {
+            PairingCurve::Bn254 => [
+                "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+                "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+                "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+                "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+            ],
+            PairingCurve::Bls12_381 => [
+                "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+                "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e",
+                "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+                "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be",
+            ],
+        };
+        let fp2 = |c0, c1| {
+            let parse = |hex| BigUint::from_hex(hex).expect("generator constants are valid hex");
+            BigFp2::new(
+                BigFelt::new(parse(c0), self.p()),
+                BigFelt::new(parse(c1), self.p()),
+            )
+        };
+        TwistPoint::new(
+            fp2(coordinates[0], coordinates[1]),
+            fp2(coordinates[2], coordinates[3]),
+            self.twist_b(),
+        )
+        .unwrap()
+    }
+
+    // Q is in G2 when rQ = O
+    pub fn is_in_g2(&self, q: &TwistPoint) -> bool {
+        q.b() == self.twist_b() && (*q * self.r()).is_infinity()
+    }
+
+    fn twist_type(&self) -> TwistType {
+        match self {
+            PairingCurve::Bn254 => TwistType::D,
+            PairingCurve::Bls12_381 => TwistType::M,
+        }
+    }
+
+    // |x| and whether x is negative
+    fn x(&self) -> (u64, bool) {
+        match self {
+            PairingCurve::Bn254 => (0x44e992b44a6909f1, false),
+            PairingCurve::Bls12_381 => (0xd201000000010000, true),
+        }
+    }
+
+    // 6x + 2 for BN curves and |x| for BLS12 curves
+    fn ate_loop_count(&self) -> BigUint {
+        let (x, _) = self.x();
+        match self {
+            PairingCurve::Bn254 => {
+                BigUint::from_u64(6) * BigUint::from_u64(x) + BigUint::from_u64(2)
+            }
+            PairingCurve::Bls12_381 => BigUint::from_u64(x),
+        }
+    }
+
+    // Computed once per curve
+    fn frobenius_constants(&self) -> FrobeniusConstants {
+        static BN254: OnceLock<FrobeniusConstants> = OnceLock::new();
+        static BLS12_381: OnceLock<FrobeniusConstants> = OnceLock::new();
+        let constants = match self {
+            PairingCurve::Bn254 => &BN254,
+            PairingCurve::Bls12_381 => &BLS12_381,
+        };
+        *constants.get_or_init(|| {
+            let (exponent, _) = (self.p() - BigUint::one()).div_rem_u64(6);
+            let gamma = self.xi().pow(&exponent);
+            // xi^((p - 1) / 3) and xi^((p - 1) / 2)
+            let (mut gamma_x, mut gamma_y) = (gamma * gamma, gamma * gamma * gamma);
+            if self.twist_type() == TwistType::M {
+                gamma_x = gamma_x.inverse().unwrap();
+                gamma_y = gamma_y.inverse().unwrap();
+            }
+            FrobeniusConstants {
+                gamma,
+                gamma_x,
+                gamma_y,
+            }
+        })
+    }
+}
+
+// The optimal Ate pairing e: G1 x G2 -> GT, bilinear and non-degenerate on
+// the subgroups of order r
+pub fn optimal_ate_pairing(curve: PairingCurve, p: &BigECPoint, q: &TwistPoint) -> Fp12 {
+    assert!(
+        curve.is_in_g2(q),
+        "Q must be in G2 of {}",
+        curve.named_curve().name()
+    );
+    final_exponentiation(curve, &miller_loop(curve, p, q))
+}
+
+// f_{6x+2,Q}(P) l_{T,pi(Q)}(P) l_{T',-pi^2(Q)}(P) for BN curves and
+// f_{x,Q}(P) for BLS12 curves, dropping the factors in proper subfields that
+// the final exponentiation sends to 1. The shortened loops rely on Q having
+// order r, so callers multiplying several loops must check is_in_g2 first.
+pub fn miller_loop(curve: PairingCurve, p: &BigECPoint, q: &TwistPoint) -> Fp12 {
+    assert!(
+        p.b() == curve.named_curve().b() && p.a().is_zero(),
+        "P must be a point on {}",
+        curve.named_curve().name()
+    );
+    assert!(
+        q.b() == curve.twist_b(),
+        "Q must be a point on the twist of {}",
+        curve.named_curve().name()
+    );
+
+    let xi = curve.xi();
+    if p.is_infinity() || q.is_infinity() {
+        return Fp12::one(xi);
+    }
+
+    let count = curve.ate_loop_count();
+    let mut f = Fp12::one(xi);
+    let mut t = *q;
+    for i in (0..count.bits() - 1).rev() {
+        f = f * f * line_function(curve, &t, &t, p);
+        t += t;
+        if count.bit(i) {
+            f = f * line_function(curve, &t, q, p);
+            t += *q;
+        }
+    }
+
+    match curve {
+        // [6x + 2]Q + pi(Q) - pi^2(Q) = 0
+        PairingCurve::Bn254 => {
+            let q1 = twist_frobenius(curve, q);
+            let q2 = -twist_frobenius(curve, &q1);
+            f = f * line_function(curve, &t, &q1, p);
+            t += q1;
+            f * line_function(curve, &t, &q2, p)
+        }
+        // f_{-|x|} = 1 / f_{|x|} up to a vertical line, and inverses are
+        // conjugates after the final exponentiation
+        PairingCurve::Bls12_381 => f.conjugate(),
+    }
+}
+
+// f^((p^12 - 1) / r), the easy part (p^6 - 1)(p^2 + 1) with Frobenius maps
+// and the hard part (p^4 - p^2 + 1) / r written in base p, with digits that
+// are polynomials in x
+pub fn final_exponentiation(curve: PairingCurve, f: &Fp12) -> Fp12 {
+    let gamma = curve.frobenius_constants().gamma;
+    let frobenius = |y: Fp12| y.frobenius_with(gamma);
+    let f = f.conjugate() * f.inverse().expect("Miller loop values are nonzero");
+    let m = frobenius(frobenius(f)) * f;
+
+    // m is now in the cyclotomic subgroup, where inverses are conjugates
+    let pow_x = |y: Fp12| {
+        let (x, negative) = curve.x();
+        let power = y.pow(&BigUint::from_u64(x));
+        if negative {
+            power.conjugate()
+        } else {
+            power
+        }
+    };
+    let pow_small = |y: Fp12, k: u64| y.pow(&BigUint::from_u64(k));
+
+    match curve {
+        // lambda_0 = -2 - 18x - 30x^2 - 36x^3, lambda_1 = 1 - 12x - 18x^2 - 36x^3,
+        // lambda_2 = 1 + 6x^2 and lambda_3 = 1 (Scott et al. 2009)
+        PairingCurve::Bn254 => {
+            let a = pow_x(m);
+            let b = pow_x(a);
+            let c = pow_x(b);
+            let y0 = (pow_small(m, 2) * pow_small(a, 18) * pow_small(b, 30) * pow_small(c, 36))
+                .conjugate();
+            let y1 = m * (pow_small(a, 12) * pow_small(b, 18) * pow_small(c, 36)).conjugate();
+            let y2 = m * pow_small(b, 6);
+            y0 * frobenius(y1) * frobenius(frobenius(y2)) * frobenius(frobenius(frobenius(m)))
+        }
+        // With A = (x - 1)^2 / 3, lambda_3 = A, lambda_2 = Ax, lambda_1 = A(x^2 - 1)
+        // and lambda_0 = A(x^3 - x) + 1
+        PairingCurve::Bls12_381 => {
+            let (x, _) = curve.x();
+            let x_minus_one = BigUint::from_u64(x + 1);
+            let (a_exponent, _) = (x_minus_one * x_minus_one).div_rem_u64(3);
+            let a = m.pow(&a_exponent);
+            let b = pow_x(a);
+            let c = pow_x(b) * a.conjugate();
+            let d = pow_x(c) * m;
+            d * frobenius(c) * frobenius(frobenius(b)) * frobenius(frobenius(frobenius(a)))
+        }
+    }
+}
+
+// The line through T and S (tangent if T = S) on E(F_p^12) evaluated at P,
+// computed on the twist
+fn line_function(curve: PairingCurve, t: &TwistPoint, s: &TwistPoint, p: &BigECPoint) -> Fp12 {
+    let xi = curve.xi();
+    // Vertical lines x_P - x_T are in F_p^6 and vanish in the final
+    // exponentiation
+    if t.x() == s.x() && t.y() != s.y() {
+        return Fp12::one(xi);
+    }
+
+    let slope = if t == s {
+        let three = BigFp2::from_u64(3, 0, curve.p());
+        three * t.x() * t.x() / (t.y() + t.y())
+    } else {
+        (s.y() - t.y()) / (s.x() - t.x())
+    };
+    let zero = BigFp2::zero(curve.p());
+    let y = BigFp2::from_big_felt(p.y());
+    let c = slope * t.x() - t.y();
+
+    let coefficients = match curve.twist_type() {
+        // y_P - slope x_P w + c w^3
+        TwistType::D => [y, -slope * p.x(), zero, c, zero, zero],
+        // Multiplied by w^3, which is in F_p^4: c - slope x_P w^2 + y_P w^3
+        TwistType::M => [c, zero, -slope * p.x(), y, zero, zero],
+    };
+    Fp12::from_coefficients(coefficients, xi)
+}
+
+// The p-power Frobenius of E(F_p^12) carried to the twist,
+// (conj(x) xi^((p - 1) / 3), conj(y) xi^((p - 1) / 2)) for D-type twists
+fn twist_frobenius(curve: PairingCurve, q: &TwistPoint) -> TwistPoint {
+    if q.is_infinity() {
+        return *q;
+    }
+    let constants = curve.frobenius_constants();
+    TwistPoint::new(
+        q.x().conjugate() * constants.gamma_x,
+        q.y().conjugate() * constants.gamma_y,
+        q.b(),
+    )
+    .expect("the Frobenius map sends the twist to itself")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::felt::fp6::Fp6;
+
+    // go-ethereum's bn256Pairing precompile cases from EIP-197. Each 192 byte
+    // pair is P = (x, y) and Q = (x.c1, x.c0, y.c1, y.c0) with zeros for
+    // infinity, and the output is 1 exactly when the pairings multiply to 1.
+    #[test]
+    fn test_bn254_eip197_vectors() {
+        let curve = PairingCurve::Bn254;
+        let (a, b) = (curve.g1().a(), curve.g1().b());
+        let cases: serde_json::Value =
+            serde_json::from_str(include_str!("../../test-data/bn254_pairing.json")).unwrap();
+        for case in cases.as_array().unwrap() {
+            let input = case["Input"].as_str().unwrap();
+            let word = |i: usize| {
+                let hex = &input[64 * i..64 * (i + 1)];
+                BigFelt::new(BigUint::from_hex(hex).unwrap(), curve.p())
+            };
+
+            let mut f = Fp12::one(curve.xi());
+            for pair in 0..input.len() / 384 {
+                let w = |i: usize| word(6 * pair + i);
+                let p = if w(0).is_zero() && w(1).is_zero() {
+                    BigECPoint::infinity(a, b)
+                } else {
+                    BigECPoint::new(w(0), w(1), a, b).unwrap()
+                };
+                let (x, y) = (BigFp2::new(w(3), w(2)), BigFp2::new(w(5), w(4)));
+                let q = if x.is_zero() && y.is_zero() {
+                    TwistPoint::infinity(curve.twist_b())
+                } else {
+                    TwistPoint::new(x, y, curve.twist_b()).unwrap()
+                };
+                f = f * miller_loop(curve, &p, &q);
+            }
+
+            let expected = case["Expected"].as_str().unwrap().ends_with('1');
+            let name = case["Name"].as_str().unwrap();
+            assert_eq!(
+                final_exponentiation(curve, &f).is_one(),
+                expected,
+                "{}",
+                name
+            );
+        }
+    }
+
+    // Gt::generator() of the bls12_381 crate, whose final exponentiation
+    // computes e(G1, G2)^3. The file keeps its Montgomery limbs x * 2^384.
+    #[test]
+    fn test_bls12_381_gt_generator() {
+        let curve = PairingCurve::Bls12_381;
+        let data: serde_json::Value =
+            serde_json::from_str(include_str!("../../test-data/bls12_381_gt_generator.json"))
+                .unwrap();
+        let r_inverse = BigFelt::from_u64(2, curve.p())
+            .pow(&BigUint::from_u64(384))
+            .inverse()
+            .unwrap();
+        let values: Vec<BigFelt> = data["Coefficients"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|coefficient| {
+                let limbs: Vec<u64> = coefficient["MontgomeryLimbs"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|limb| {
+                        let hex = limb.as_str().unwrap().trim_start_matches("0x");
+                        u64::from_str_radix(hex, 16).unwrap()
+                    })
+                    .collect();
+                let limbs = limbs.try_into().unwrap();
+                BigFelt::new(BigUint::from_limbs(limbs), curve.p()) * r_inverse
+            })
+            .collect();
+
+        // Stored as c0.c0.c0, c0.c0.c1, c0.c1.c0, ..., c1.c2.c1
+        let fp2 = |i: usize| BigFp2::new(values[2 * i], values[2 * i + 1]);
+        let fp6 = |i: usize| Fp6::new(fp2(3 * i), fp2(3 * i + 1), fp2(3 * i + 2), curve.xi());
+        let generator = Fp12::new(fp6(0), fp6(1));
+
+        let e = optimal_ate_pairing(curve, &curve.g1(), &curve.g2());
+        assert_eq!(e.pow(&BigUint::from_u64(3)), generator);
+    }
+
+    #[test]
+    fn test_generators_have_order_r() {
+        for curve in [PairingCurve::Bn254, PairingCurve::Bls12_381] {
+            assert!((curve.g1() * curve.r()).is_infinity());
+            assert!((curve.g2() * curve.r()).is_infinity());
+        }
+    }
+
+    // The Frobenius acts on G2 as multiplication by p
+    #[test]
+    fn test_twist_frobenius() {
+        for curve in [PairingCurve::Bn254, PairingCurve::Bls12_381] {
+            let q = curve.g2();
+            assert_eq!(twist_frobenius(curve, &q), q * curve.p().rem(&curve.r()));
+        }
+    }
+
+    #[test]
+    fn test_bilinearity() {
+        for curve in [PairingCurve::Bn254, PairingCurve::Bls12_381] {
+            let (p, q) = (curve.g1(), curve.g2());
+            let e = optimal_ate_pairing(curve, &p, &q);
+            assert!(!e.is_one());
+            assert!(e.pow(&curve.r()).is_one());
+
+            let (a, b) = (BigUint::from_u64(12345), BigUint::from_u64(67890));
+            assert_eq!(
+                optimal_ate_pairing(curve, &(p * a), &(q * b)),
+                e.pow(&(a * b))
+            );
+            // The product check of the EIP-197 precompile
+            let product = e * optimal_ate_pairing(curve, &-p, &q);
+            assert!(product.is_one());
+        }
+    }
+
+    #[test]
+    fn test_pairing_with_infinity() {
+        let curve = PairingCurve::Bn254;
+        let infinity = BigECPoint::infinity(curve.g1().a(), curve.g1().b());
+        assert!(optimal_ate_pairing(curve, &infinity, &curve.g2()).is_one());
+        let infinity = TwistPoint::infinity(curve.twist_b());
+        assert!(optimal_ate_pairing(curve, &curve.g1(), &infinity).is_one());
+    }
+
+    // sqrt in F_p^2 for p = 3 (mod 4), Algorithm 9 of Adj and
+    // Rodriguez-Henriquez
+    fn fp2_sqrt(a: BigFp2) -> Option<BigFp2> {
+        let p = a.modulus();
+        let (exponent, _) = (p - BigUint::from_u64(3)).div_rem_u64(4);
+        let a1 = a.pow(&exponent);
+        let alpha = a1 * a1 * a;
+        let x0 = a1 * a;
+        let root = if alpha == -BigFp2::one(p) {
+            BigFp2::from_u64(0, 1, p) * x0
+        } else {
+            let (half, _) = (p - BigUint::one()).div_rem_u64(2);
+            (BigFp2::one(p) + alpha).pow(&half) * x0
+        };
+        (root * root == a).then_some(root)
+    }
+
+    // The twists have cofactors, so most of their points are not in G2
+    fn point_outside_g2(curve: PairingCurve) -> TwistPoint {
+        (1..)
+            .find_map(|k| {
+                let x = BigFp2::from_u64(k, 1, curve.p());
+                let y = fp2_sqrt(x * x * x + curve.twist_b())?;
+                TwistPoint::new(x, y, curve.twist_b()).ok()
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_is_in_g2() {
+        for curve in [PairingCurve::Bn254, PairingCurve::Bls12_381] {
+            assert!(curve.is_in_g2(&curve.g2()));
+            assert!(curve.is_in_g2(&TwistPoint::infinity(curve.twist_b())));
+            assert!(!curve.is_in_g2(&point_outside_g2(curve)));
+        }
+        let curve = PairingCurve::Bls12_381;
+        assert!(!curve.is_in_g2(&PairingCurve::Bn254.g2()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Q must be in G2 of BN254")]
+    fn test_point_outside_g2_should_panic() {
+        let curve = PairingCurve::Bn254;
+        optimal_ate_pairing(curve, &curve.g1(), &point_outside_g2(curve));
+    }
+
+    // The cached constant matches a fresh xi^((p - 1) / 6)
+    #[test]
+    fn test_frobenius_constants() {
+        for curve in [PairingCurve::Bn254, PairingCurve::Bls12_381] {
+            let e = optimal_ate_pairing(curve, &curve.g1(), &curve.g2());
+            assert_eq!(
+                e.frobenius_with(curve.frobenius_constants().gamma),
+                e.frobenius()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Q must be a point on the twist of BLS12-381")]
+    fn test_point_from_other_curve_should_panic() {
+        let curve = PairingCurve::Bls12_381;
+        miller_loop(curve, &curve.g1(), &PairingCurve::Bn254.g2());
+    }
+}
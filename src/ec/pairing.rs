@@ -0,0 +1,397 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Mul, Neg},
+};
+
+use crate::felt::{felt::Felt, fp2::Fp2};
+
+use super::{ec_errors::ECError, ec_point::ECPoint};
+
+// Point on y^2 = x^3 + ax + b (a, b in F_p) with coordinates in F_p^2, where
+// the second argument of a pairing lives for embedding degree 2
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fp2Point {
+    x: Fp2,
+    y: Fp2,
+    a: Felt,
+    b: Felt,
+    infinity: bool,
+}
+
+impl Fp2Point {
+    pub fn new(x: Fp2, y: Fp2, a: Felt, b: Felt) -> Result<Self, ECError> {
+        let beta = x.nonresidue();
+        let lhs = y * y;
+        let rhs = x * x * x + x * a + Fp2::from_felt(b, beta);
+        if lhs != rhs {
+            return Err(ECError::Fp2PointNotOnCurve(
+                x.to_string(),
+                y.to_string(),
+                a.value(),
+                b.value(),
+            ));
+        }
+        Ok(Fp2Point {
+            x,
+            y,
+            a,
+            b,
+            infinity: false,
+        })
+    }
+
+    pub fn infinity(a: Felt, b: Felt, nonresidue: Felt) -> Self {
+        Fp2Point {
+            x: Fp2::zero(nonresidue),
+            y: Fp2::zero(nonresidue),
+            a,
+            b,
+            infinity: true,
+        }
+    }
+
+    // The same point viewed over F_p^2 = F_p[u] / (u^2 - nonresidue)
+    pub fn from_point(point: &ECPoint, nonresidue: Felt) -> Self {
+        if point.is_infinity() {
+            return Fp2Point::infinity(point.a(), point.b(), nonresidue);
+        }
+        Fp2Point {
+            x: Fp2::from_felt(point.x(), nonresidue),
+            y: Fp2::from_felt(point.y(), nonresidue),
+            a: point.a(),
+            b: point.b(),
+            infinity: false,
+        }
+    }
+
+    pub fn x(&self) -> Fp2 {
+        self.x
+    }
+
+    pub fn y(&self) -> Fp2 {
+        self.y
+    }
+
+    pub fn a(&self) -> Felt {
+        self.a
+    }
+
+    pub fn b(&self) -> Felt {
+        self.b
+    }
+
+    pub fn nonresidue(&self) -> Felt {
+        self.x.nonresidue()
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.infinity
+    }
+
+    fn identity(&self) -> Self {
+        Fp2Point::infinity(self.a, self.b, self.nonresidue())
+    }
+}
+
+impl Add for Fp2Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.a != other.a || self.b != other.b || self.nonresidue() != other.nonresidue() {
+            panic!("Points {}, {} are not on the same curve", self, other);
+        }
+        if self.infinity {
+            return other;
+        }
+        if other.infinity {
+            return self;
+        }
+        if self.x == other.x && (self.y + other.y).is_zero() {
+            return self.identity();
+        }
+
+        let lambda = slope(&self, &other);
+        let x = lambda * lambda - self.x - other.x;
+        let y = lambda * (self.x - x) - self.y;
+        Fp2Point {
+            x,
+            y,
+            a: self.a,
+            b: self.b,
+            infinity: false,
+        }
+    }
+}
+
+impl Neg for Fp2Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fp2Point { y: -self.y, ..self }
+    }
+}
+
+impl Mul<u64> for Fp2Point {
+    type Output = Self;
+
+    fn mul(self, k: u64) -> Self {
+        let mut result = self.identity();
+        for i in (0..u64::BITS - k.leading_zeros()).rev() {
+            result = result + result;
+            if (k >> i) & 1 == 1 {
+                result = result + self;
+            }
+        }
+        result
+    }
+}
+
+impl Display for Fp2Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.infinity {
+            return write!(f, "Infinity");
+        }
+        write!(
+            f,
+            "({} + {}u, {} + {}u)",
+            self.x.c0().value(),
+            self.x.c1().value(),
+            self.y.c0().value(),
+            self.y.c1().value()
+        )
+    }
+}
+
+// Slope of the line through s and t, the tangent if they are equal. They
+// must not be inverses of each other.
+fn slope(s: &Fp2Point, t: &Fp2Point) -> Fp2 {
+    if s.x == t.x {
+        let three_x2 = s.x * s.x * Felt::new(3, s.x.modulus());
+        (three_x2 + Fp2::from_felt(s.a, s.nonresidue())) / (s.y + s.y)
+    } else {
+        (t.y - s.y) / (t.x - s.x)
+    }
+}
+
+// g_{S,T}(Q) = l(Q) / v(Q) for the line l through S and T and the vertical v
+// through S + T, so div(g) = (S) + (T) - (S + T) - (O). None if Q is a zero
+// or pole.
+fn line_function(s: &Fp2Point, t: &Fp2Point, q: &Fp2Point) -> Option<Fp2> {
+    let one = Fp2::one(s.nonresidue());
+    if s.infinity || t.infinity {
+        return Some(one);
+    }
+    if s.x == t.x && (s.y + t.y).is_zero() {
+        let vertical = q.x - s.x;
+        return (!vertical.is_zero()).then_some(vertical);
+    }
+
+    let lambda = slope(s, t);
+    let line = q.y - s.y - lambda * (q.x - s.x);
+    let vertical = q.x - (*s + *t).x;
+    if line.is_zero() || vertical.is_zero() {
+        return None;
+    }
+    Some(line / vertical)
+}
+
+// Miller's algorithm for f_{n,P}(Q) with div(f) = n(P) - (nP) - (n - 1)(O),
+// built up bit by bit from f_{i+j} = f_i f_j g_{iP,jP}. None if Q hits a
+// zero or pole of one of the line functions.
+pub fn miller_loop(p: &Fp2Point, q: &Fp2Point, n: u64) -> Option<Fp2> {
+    assert!(n > 0, "Miller's loop needs a positive n");
+    let mut f = Fp2::one(p.nonresidue());
+    let mut t = *p;
+    for i in (0..u64::BITS - n.leading_zeros() - 1).rev() {
+        f = f * f * line_function(&t, &t, q)?;
+        t = t + t;
+        if (n >> i) & 1 == 1 {
+            f = f * line_function(&t, p, q)?;
+            t = t + *p;
+        }
+    }
+    Some(f)
+}
+
+// Reduced Tate pairing f_{r,P}(Q)^((p^2 - 1) / r) for P of order r with
+// r | p + 1, i.e. embedding degree 2. The result is an r-th root of unity in
+// F_p^2, and the final exponentiation is (f^p / f)^((p + 1) / r).
+pub fn tate_pairing(p: &Fp2Point, q: &Fp2Point, r: u64) -> Option<Fp2> {
+    let modulus = p.a.modulus();
+    assert!(
+        (modulus + 1).is_multiple_of(r),
+        "Tate pairing needs embedding degree 2, r must divide p + 1"
+    );
+    if p.infinity || q.infinity {
+        return Some(Fp2::one(p.nonresidue()));
+    }
+
+    let f = miller_loop(p, q, r)?;
+    let f = f.frobenius() / f;
+    Some(f.pow((modulus + 1) / r))
+}
+
+// Weil pairing (-1)^r f_{r,P}(Q) / f_{r,Q}(P) for P, Q in E[r]. It is 1 if
+// P and Q are dependent and None if Q hits a zero or pole, which happens
+// for some dependent P and Q.
+pub fn weil_pairing(p: &Fp2Point, q: &Fp2Point, r: u64) -> Option<Fp2> {
+    let one = Fp2::one(p.nonresidue());
+    if p.infinity || q.infinity || p == q {
+        return Some(one);
+    }
+
+    let value = miller_loop(p, q, r)? / miller_loop(q, p, r)?;
+    Some(if r % 2 == 1 { -value } else { value })
+}
+
+// Distortion map to an independent point over F_p^2 on the supersingular
+// curves y^2 = x^3 + ax with p = 3 (mod 4), (x, y) -> (-x, uy) with u^2 = -1,
+// and y^2 = x^3 + b with p = 2 (mod 3), (x, y) -> (zeta x, y) with zeta a
+// primitive cube root of unity. None for other curves.
+pub fn distortion_map(point: &ECPoint) -> Option<Fp2Point> {
+    let (a, b) = (point.a(), point.b());
+    let modulus = a.modulus();
+    let beta = Fp2::default_nonresidue(modulus);
+    if point.is_infinity() {
+        return Some(Fp2Point::infinity(a, b, beta));
+    }
+    let x = Fp2::from_felt(point.x(), beta);
+    let y = Fp2::from_felt(point.y(), beta);
+
+    if b.value() == 0 && modulus % 4 == 3 {
+        let u = Fp2::new(Felt::new(0, modulus), Felt::new(1, modulus), beta);
+        return Fp2Point::new(-x, u * y, a, b).ok();
+    }
+    if a.value() == 0 && modulus % 3 == 2 {
+        // zeta = (-1 + sqrt(-3)) / 2 with sqrt(-3) = cu and c^2 beta = -3
+        let c = (-Felt::new(3, modulus) / beta).sqrt()?;
+        let two = Felt::new(2, modulus);
+        let zeta = Fp2::new(-Felt::new(1, modulus) / two, c / two, beta);
+        return Fp2Point::new(zeta * x, y, a, b).ok();
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // First point on y^2 = x^3 + ax + b times the cofactor that is not
+    // infinity
+    fn point_of_order(a: u64, b: u64, modulus: u64, cofactor: u64) -> ECPoint {
+        let (a, b) = (Felt::new(a, modulus), Felt::new(b, modulus));
+        (0..modulus)
+            .map(|x| Felt::new(x, modulus))
+            .find_map(|x| {
+                let y = (x.pow(3) + a * x + b).sqrt()?;
+                let p = ECPoint::new(x, y, a, b).unwrap() * cofactor;
+                (!p.is_infinity()).then_some(p)
+            })
+            .unwrap()
+    }
+
+    // y^2 = x^3 + x mod 43 has 44 = 4 * 11 points, r = 11 divides p + 1
+    fn point_of_order_11() -> ECPoint {
+        let p = point_of_order(1, 0, 43, 4);
+        assert_eq!(p.order(), 11);
+        p
+    }
+
+    // y^2 = x^3 + 1 mod 101 has 102 = 6 * 17 points
+    fn point_of_order_17() -> ECPoint {
+        let p = point_of_order(0, 1, 101, 6);
+        assert_eq!(p.order(), 17);
+        p
+    }
+
+    fn lift(p: &ECPoint) -> Fp2Point {
+        Fp2Point::from_point(p, Fp2::default_nonresidue(p.a().modulus()))
+    }
+
+    #[test]
+    fn test_distortion_map() {
+        for (p, r) in [(point_of_order_11(), 11), (point_of_order_17(), 17)] {
+            let q = distortion_map(&p).unwrap();
+            assert!(!q.x().is_felt() || !q.y().is_felt());
+            assert!((q * r).is_infinity());
+            // Distortion is a homomorphism
+            assert_eq!(distortion_map(&(p * 3)).unwrap(), q * 3);
+        }
+
+        let a = -Felt::new(3, 1021);
+        let p = ECPoint::new(Felt::new(379, 1021), Felt::new(1011, 1021), a, a).unwrap();
+        assert_eq!(distortion_map(&p), None);
+    }
+
+    #[test]
+    fn test_fp2_point_arithmetic() {
+        let p = point_of_order_11();
+        let q = distortion_map(&p).unwrap();
+        assert_eq!(lift(&p) * 3 + lift(&p), lift(&(p * 4)));
+        assert_eq!(q + -q, Fp2Point::infinity(p.a(), p.b(), q.nonresidue()));
+        assert_eq!(q * 12, q);
+        assert!(Fp2Point::new(q.x(), q.x(), p.a(), p.b()).is_err());
+    }
+
+    #[test]
+    fn test_tate_pairing_is_bilinear_and_non_degenerate() {
+        for (p, r) in [(point_of_order_11(), 11), (point_of_order_17(), 17)] {
+            let q = distortion_map(&p).unwrap();
+            let e = tate_pairing(&lift(&p), &q, r).unwrap();
+            let one = Fp2::one(e.nonresidue());
+            assert_ne!(e, one);
+            assert_eq!(e.pow(r), one);
+
+            for (i, j) in [(2, 1), (1, 3), (5, 7), (r - 1, 2)] {
+                let lhs = tate_pairing(&lift(&(p * i)), &(q * j), r).unwrap();
+                assert_eq!(lhs, e.pow(i * j), "i = {}, j = {}", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_weil_pairing() {
+        for (p, r) in [(point_of_order_11(), 11), (point_of_order_17(), 17)] {
+            let q = distortion_map(&p).unwrap();
+            let e = weil_pairing(&lift(&p), &q, r).unwrap();
+            let one = Fp2::one(e.nonresidue());
+            assert_ne!(e, one);
+            assert_eq!(e.pow(r), one);
+
+            // Alternating: e(P, P) = 1 and e(Q, P) = e(P, Q)^-1
+            assert_eq!(weil_pairing(&lift(&p), &lift(&p), r), Some(one));
+            assert_eq!(weil_pairing(&q, &lift(&p), r).unwrap() * e, one);
+
+            for (i, j) in [(2, 1), (3, 4), (r - 1, 5)] {
+                let lhs = weil_pairing(&lift(&(p * i)), &(q * j), r).unwrap();
+                assert_eq!(lhs, e.pow(i * j), "i = {}, j = {}", i, j);
+            }
+        }
+    }
+
+    // MOV: the pairing maps the DLP kP = R on the curve to e(P, Q)^k =
+    // e(R, Q) in F_p^2
+    #[test]
+    fn test_mov_reduction() {
+        let p = point_of_order_17();
+        let q = distortion_map(&p).unwrap();
+        let k = 13;
+        let g = tate_pairing(&lift(&p), &q, 17).unwrap();
+        let h = tate_pairing(&lift(&(p * k)), &q, 17).unwrap();
+        assert_eq!((0..17).find(|&i| g.pow(i) == h), Some(k));
+    }
+
+    #[test]
+    #[should_panic(expected = "Tate pairing needs embedding degree 2, r must divide p + 1")]
+    fn test_tate_pairing_with_wrong_embedding_degree_should_panic() {
+        let p = point_of_order_11();
+        tate_pairing(&lift(&p), &lift(&p), 13);
+    }
+
+    #[test]
+    #[should_panic(expected = "Miller's loop needs a positive n")]
+    fn test_miller_loop_with_zero_should_panic() {
+        let p = lift(&point_of_order_11());
+        miller_loop(&p, &p, 0);
+    }
+}
@@ -0,0 +1,255 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::felt::{felt::Felt, felt_errors::FeltError};
+
+use super::ec_point::ECPoint;
+
+// Integer modulo the order n of a point's subgroup. Since n * P = 0, k * P
+// only depends on k mod n, so keys and signature arithmetic live here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Scalar {
+    value: Felt,
+}
+
+impl Scalar {
+    pub fn new(value: u64, order: u64) -> Self {
+        Scalar {
+            value: Felt::new(value, order),
+        }
+    }
+
+    // Negative values wrap around, -1 is n - 1
+    pub fn from_i64(value: i64, order: u64) -> Self {
+        let scalar = Scalar::new(value.unsigned_abs(), order);
+        if value < 0 {
+            -scalar
+        } else {
+            scalar
+        }
+    }
+
+    // Scalar modulo the order of point, which is computed by brute force
+    pub fn for_point(value: u64, point: &ECPoint) -> Self {
+        Scalar::new(value, point.order())
+    }
+
+    pub fn zero(order: u64) -> Self {
+        Scalar::new(0, order)
+    }
+
+    pub fn one(order: u64) -> Self {
+        Scalar::new(1, order)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value.value()
+    }
+
+    pub fn order(&self) -> u64 {
+        self.value.modulus()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value() == 0
+    }
+
+    // Exists when the value is coprime to the order, always for prime n
+    pub fn inverse(&self) -> Result<Self, FeltError> {
+        Ok(Scalar {
+            value: self.value.inverse()?,
+        })
+    }
+
+    pub fn pow(&self, exponent: u64) -> Self {
+        Scalar {
+            value: self.value.pow(exponent),
+        }
+    }
+
+    // Reducing modulo n is only sound when nP = 0. Checking costs a scalar
+    // multiplication, so it only runs in debug builds.
+    fn debug_check_point(&self, point: &ECPoint) {
+        debug_assert!(
+            (*point * self.order()).is_infinity(),
+            "The point's order must divide the Scalar order {}",
+            self.order()
+        );
+    }
+
+    fn check_order(&self, other: &Scalar, operation: &str) {
+        if self.order() != other.order() {
+            panic!(
+                "Cannot {} two Scalar values with different orders",
+                operation
+            );
+        }
+    }
+}
+
+impl Add for Scalar {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.check_order(&other, "add");
+        Scalar {
+            value: self.value + other.value,
+        }
+    }
+}
+
+impl Sub for Scalar {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.check_order(&other, "subtract");
+        Scalar {
+            value: self.value - other.value,
+        }
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.check_order(&other, "multiply");
+        Scalar {
+            value: self.value * other.value,
+        }
+    }
+}
+
+impl Div for Scalar {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.check_order(&other, "divide");
+        Scalar {
+            value: self.value / other.value,
+        }
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Scalar { value: -self.value }
+    }
+}
+
+// The point's order must divide the scalar's order, e.g. the subgroup order
+// for points in that subgroup. Debug builds panic otherwise.
+impl Mul<Scalar> for ECPoint {
+    type Output = Self;
+
+    fn mul(self, other: Scalar) -> Self {
+        other.debug_check_point(&self);
+        self * other.value()
+    }
+}
+
+impl Mul<ECPoint> for Scalar {
+    type Output = ECPoint;
+
+    fn mul(self, other: ECPoint) -> ECPoint {
+        self.debug_check_point(&other);
+        other * self.value()
+    }
+}
+
+impl Display for Scalar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (mod {})", self.value(), self.order())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // y^2 = x^3 - 3x - 3 mod 1021 has prime order 1039
+    fn generator() -> ECPoint {
+        let modulus = 1021;
+        let a = -Felt::new(3, modulus);
+        let b = -Felt::new(3, modulus);
+        ECPoint::new(Felt::new(379, modulus), Felt::new(1011, modulus), a, b).unwrap()
+    }
+
+    #[test]
+    fn test_new_reduces_modulo_order() {
+        assert_eq!(Scalar::new(1039 + 5, 1039), Scalar::new(5, 1039));
+        assert_eq!(Scalar::from_i64(-1, 1039).value(), 1038);
+        assert_eq!(Scalar::from_i64(-1040, 1039).value(), 1038);
+    }
+
+    #[test]
+    fn test_for_point() {
+        let g = generator();
+        let k = Scalar::for_point(2000, &g);
+        assert_eq!(k.order(), 1039);
+        assert_eq!(k.value(), 961);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let n = 1039;
+        let a = Scalar::new(1000, n);
+        let b = Scalar::new(100, n);
+        assert_eq!(a + b, Scalar::new(61, n));
+        assert_eq!(b - a, Scalar::new(139, n));
+        assert_eq!(a * b, Scalar::new(100000 % n, n));
+        assert_eq!(a / b * b, a);
+        assert_eq!(a * a.inverse().unwrap(), Scalar::one(n));
+        assert!((a + -a).is_zero());
+        assert_eq!(a.pow(n - 1), Scalar::one(n));
+    }
+
+    #[test]
+    fn test_inverse_of_zero() {
+        assert!(Scalar::zero(1039).inverse().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot add two Scalar values with different orders")]
+    fn test_add_with_different_orders_should_panic() {
+        let _ = Scalar::new(1, 1039) + Scalar::new(1, 13);
+    }
+
+    #[test]
+    fn test_point_multiplication_is_modulo_order() {
+        let g = generator();
+        let k = Scalar::new(655, 1039);
+        assert_eq!(g * k, g * 655);
+        assert_eq!(k * g, g * (655 + 1039));
+        assert_eq!(g * -k, -(g * 655));
+        assert_eq!(g * Scalar::from_i64(-1, 1039), -g);
+    }
+
+    // G has order 1039, a scalar mod 13 does not describe a multiple of it
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "The point's order must divide the Scalar order 13")]
+    fn test_point_multiplication_with_mismatched_order_should_panic() {
+        let _ = generator() * Scalar::new(5, 13);
+    }
+
+    // (k1 + k2)G = k1G + k2G and (k1k2)G = k1(k2G) hold for scalars mod n
+    #[test]
+    fn test_scalar_arithmetic_matches_group_law() {
+        let g = generator();
+        let k1 = Scalar::new(900, 1039);
+        let k2 = Scalar::new(700, 1039);
+        assert_eq!(g * (k1 + k2), g * k1 + g * k2);
+        assert_eq!(g * (k1 * k2), (g * k2) * k1);
+        assert_eq!((g * k1) * k1.inverse().unwrap(), g);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Scalar::from_i64(-2, 13)), "11 (mod 13)");
+    }
+}
@@ -0,0 +1,313 @@
+use super::ec_point::ECPoint;
+
+// Scalar multiplication algorithms for ECPoint, see ECPoint::mul_with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarMulMethod {
+    // Left-to-right double-and-add, what Mul<u64> does
+    DoubleAndAdd,
+    // Width-w NAF with a table of 2^(w-2) odd multiples
+    Wnaf(usize),
+    MontgomeryLadder,
+}
+
+// Window width of the per-point wNAF tables in Straus
+const STRAUS_WINDOW: usize = 4;
+
+// Below this many terms Straus is faster than Pippenger
+const PIPPENGER_THRESHOLD: usize = 64;
+
+// Width-w non-adjacent form of k, least significant digit first. Every
+// nonzero digit is odd with |d| < 2^(w-1) and is followed by at least w - 1
+// zeros, so on average only 1 in w + 1 digits is nonzero.
+pub fn wnaf(k: u64, w: usize) -> Vec<i64> {
+    assert!((2..=16).contains(&w), "wNAF width must be between 2 and 16");
+
+    let window = 1_i128 << w;
+    let mut k = k as i128;
+    let mut digits = Vec::new();
+
+    while k > 0 {
+        let digit = if k % 2 == 1 {
+            // k mod 2^w in (-2^(w-1), 2^(w-1))
+            let d = k % window;
+            if d >= window / 2 {
+                d - window
+            } else {
+                d
+            }
+        } else {
+            0
+        };
+        k -= digit;
+        digits.push(digit as i64);
+        k >>= 1;
+    }
+
+    digits
+}
+
+impl ECPoint {
+    pub fn mul_with(&self, k: u64, method: ScalarMulMethod) -> ECPoint {
+        match method {
+            ScalarMulMethod::DoubleAndAdd => *self * k,
+            ScalarMulMethod::Wnaf(w) => self.mul_wnaf(k, w),
+            ScalarMulMethod::MontgomeryLadder => self.montgomery_ladder(k),
+        }
+    }
+
+    // Negation is free, so only the positive odd multiples are stored
+    pub fn mul_wnaf(&self, k: u64, w: usize) -> ECPoint {
+        let digits = wnaf(k, w);
+        let table = self.odd_multiples(1 << (w - 2));
+
+        let mut result = ECPoint::infinity(self.a(), self.b());
+        for &digit in digits.iter().rev() {
+            result += result;
+            if digit > 0 {
+                result += table[(digit / 2) as usize];
+            } else if digit < 0 {
+                result += -table[(-digit / 2) as usize];
+            }
+        }
+
+        result
+    }
+
+    // Sum of k_i * P_i, Straus for few terms and Pippenger for many. Panics
+    // if there are no terms since the curve would be unknown.
+    pub fn multi_scalar_mul(terms: &[(u64, ECPoint)]) -> ECPoint {
+        if terms.len() < PIPPENGER_THRESHOLD {
+            ECPoint::straus(terms)
+        } else {
+            ECPoint::pippenger(terms)
+        }
+    }
+
+    // Straus (Shamir's trick generalized): interleaves the wNAF expansions of
+    // all scalars so the doublings are shared
+    pub fn straus(terms: &[(u64, ECPoint)]) -> ECPoint {
+        let mut result = ECPoint::msm_identity(terms);
+        let w = STRAUS_WINDOW;
+        let expansions: Vec<(Vec<i64>, Vec<ECPoint>)> = terms
+            .iter()
+            .map(|&(k, p)| (wnaf(k, w), p.odd_multiples(1 << (w - 2))))
+            .collect();
+        let length = expansions.iter().map(|(digits, _)| digits.len()).max();
+
+        for i in (0..length.unwrap_or(0)).rev() {
+            result += result;
+            for (digits, table) in expansions.iter() {
+                match digits.get(i) {
+                    Some(&digit) if digit > 0 => result += table[(digit / 2) as usize],
+                    Some(&digit) if digit < 0 => result += -table[(-digit / 2) as usize],
+                    _ => {}
+                }
+            }
+        }
+
+        result
+    }
+
+    // Pippenger's bucket method: for each c-bit window the points are sorted
+    // into 2^c - 1 buckets by digit, and sum_j j * bucket_j is computed with
+    // two running sums instead of multiplications
+    pub fn pippenger(terms: &[(u64, ECPoint)]) -> ECPoint {
+        let identity = ECPoint::msm_identity(terms);
+        // c = log2(n) - 3 was fastest in benches/scalar_mul.rs
+        let c = (usize::BITS - terms.len().leading_zeros())
+            .saturating_sub(3)
+            .clamp(1, 16) as usize;
+        let mask = (1_u64 << c) - 1;
+        let windows = 64_usize.div_ceil(c);
+
+        let mut result = identity;
+        for window in (0..windows).rev() {
+            for _ in 0..c {
+                result += result;
+            }
+
+            let mut buckets = vec![identity; (1 << c) - 1];
+            for &(k, p) in terms {
+                let digit = (k >> (window * c)) & mask;
+                if digit != 0 {
+                    buckets[digit as usize - 1] += p;
+                }
+            }
+
+            // running = sum of buckets j..2^c - 1, so adding it once per j
+            // adds bucket_j exactly j times
+            let mut running = identity;
+            let mut window_sum = identity;
+            for &bucket in buckets.iter().rev() {
+                running += bucket;
+                window_sum += running;
+            }
+            result += window_sum;
+        }
+
+        result
+    }
+
+    fn msm_identity(terms: &[(u64, ECPoint)]) -> ECPoint {
+        let (_, p) = terms
+            .first()
+            .expect("Multi-scalar multiplication needs at least one term");
+        ECPoint::infinity(p.a(), p.b())
+    }
+
+    // P, 3P, 5P, ..., (2count - 1)P
+    pub(crate) fn odd_multiples(&self, count: usize) -> Vec<ECPoint> {
+        let double = *self + *self;
+        let mut table = Vec::with_capacity(count);
+        table.push(*self);
+        for i in 1..count {
+            table.push(table[i - 1] + double);
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::felt::felt::Felt;
+
+    fn point() -> ECPoint {
+        let modulus = 1021;
+        let a = -Felt::new(3, modulus);
+        let b = -Felt::new(3, modulus);
+        ECPoint::new(Felt::new(379, modulus), Felt::new(1011, modulus), a, b).unwrap()
+    }
+
+    #[test]
+    fn test_wnaf_digits() {
+        // 7 = 8 - 1
+        assert_eq!(wnaf(7, 2), vec![-1, 0, 0, 1]);
+        // 255 = 256 - 1
+        assert_eq!(wnaf(255, 3), vec![-1, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(wnaf(0, 4), Vec::<i64>::new());
+    }
+
+    #[test]
+    fn test_wnaf_reconstructs_scalar() {
+        for w in 2..=8 {
+            for k in [1, 2, 3, 655, 1038, 123456789, u64::MAX - 1, u64::MAX] {
+                let digits = wnaf(k, w);
+                let value = digits
+                    .iter()
+                    .rev()
+                    .fold(0_i128, |acc, &d| 2 * acc + d as i128);
+                assert_eq!(value, k as i128);
+
+                for (i, &d) in digits.iter().enumerate() {
+                    if d != 0 {
+                        assert!(d % 2 != 0 && d.abs() < 1 << (w - 1));
+                        let next = &digits[i + 1..(i + w).min(digits.len())];
+                        assert!(next.iter().all(|&d| d == 0));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "wNAF width must be between 2 and 16")]
+    fn test_wnaf_width_too_small_should_panic() {
+        wnaf(5, 1);
+    }
+
+    #[test]
+    fn test_mul_wnaf_matches_double_and_add() {
+        let p = point();
+        for w in 2..=6 {
+            for k in [0, 1, 2, 655, 1038, 1039, 1040, u64::MAX] {
+                assert_eq!(p.mul_wnaf(k, w), p * k, "k = {}, w = {}", k, w);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_with() {
+        let p = point();
+        for method in [
+            ScalarMulMethod::DoubleAndAdd,
+            ScalarMulMethod::Wnaf(4),
+            ScalarMulMethod::MontgomeryLadder,
+        ] {
+            assert_eq!(
+                p.mul_with(655, method).to_string(),
+                "(388, 60)",
+                "{:?}",
+                method
+            );
+        }
+    }
+
+    fn msm_terms(count: u64) -> Vec<(u64, ECPoint)> {
+        let p = point();
+        (0..count)
+            .map(|i| {
+                let k = (i + 1).wrapping_mul(0x9e3779b97f4a7c15) ^ (i << 7);
+                (k, p * (3 * i + 1))
+            })
+            .collect()
+    }
+
+    fn naive_msm(terms: &[(u64, ECPoint)]) -> ECPoint {
+        let (_, p) = terms[0];
+        let mut sum = ECPoint::infinity(p.a(), p.b());
+        for &(k, p) in terms {
+            sum += p * k;
+        }
+        sum
+    }
+
+    #[test]
+    fn test_straus_matches_naive_sum() {
+        for count in [1, 2, 3, 10] {
+            let terms = msm_terms(count);
+            assert_eq!(
+                ECPoint::straus(&terms),
+                naive_msm(&terms),
+                "{} terms",
+                count
+            );
+        }
+    }
+
+    #[test]
+    fn test_pippenger_matches_naive_sum() {
+        for count in [1, 2, 3, 10, 100] {
+            let terms = msm_terms(count);
+            assert_eq!(
+                ECPoint::pippenger(&terms),
+                naive_msm(&terms),
+                "{} terms",
+                count
+            );
+        }
+    }
+
+    #[test]
+    fn test_multi_scalar_mul_with_zeros_and_cancellation() {
+        let p = point();
+        // 5P + 1034P = 1039P = 0 and the zero scalar and infinity add nothing
+        let terms = [
+            (5, p),
+            (1034, p),
+            (0, p * 7),
+            (u64::MAX, ECPoint::infinity(p.a(), p.b())),
+        ];
+        assert!(ECPoint::multi_scalar_mul(&terms).is_infinity());
+        assert!(ECPoint::pippenger(&terms).is_infinity());
+
+        let terms = msm_terms(PIPPENGER_THRESHOLD as u64 + 1);
+        assert_eq!(ECPoint::multi_scalar_mul(&terms), naive_msm(&terms));
+    }
+
+    #[test]
+    #[should_panic(expected = "Multi-scalar multiplication needs at least one term")]
+    fn test_multi_scalar_mul_without_terms_should_panic() {
+        ECPoint::multi_scalar_mul(&[]);
+    }
+}
@@ -0,0 +1,464 @@
+use std::{
+    f64::consts::PI,
+    fmt::{Display, Formatter},
+};
+
+use crate::felt::{
+    felt::Felt,
+    primes::{largest_prime_factor, prime_factors},
+};
+
+use super::{
+    curve_generator::embedding_degree, ec_point::ECPoint, edwards_point::EdwardsPoint,
+    montgomery_point::MontgomeryPoint,
+};
+
+// Embedding degrees up to this are flagged, as in CurveGenerator
+const MIN_EMBEDDING_DEGREE: u64 = 20;
+
+// SafeCurves-style analysis of y^2 = x^3 + ax + b over F_p with a generator.
+// Everything is computed exactly, with points counted in O(p), so this is
+// for small fields.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecurityReport {
+    generator: ECPoint,
+    discriminant: Felt,
+    group_order: u64,
+    generator_order: u64,
+    largest_prime_factor: u64,
+    embedding_degree: Option<u64>,
+    cm_discriminant: i128,
+    twist_order: u64,
+    twist_largest_prime_factor: u64,
+    montgomery_form: bool,
+    complete_edwards_form: Option<(Felt, Felt)>,
+}
+
+impl SecurityReport {
+    pub fn analyze(generator: &ECPoint) -> Self {
+        assert!(
+            !generator.is_infinity(),
+            "Generator must not be the point at infinity"
+        );
+        let (a, b) = (generator.a(), generator.b());
+        let modulus = a.modulus();
+
+        let group_order = ECPoint::count_points(a, b);
+        let generator_order = generator.order_dividing(group_order);
+        let prime = largest_prime_factor(generator_order);
+        let twist_order = ECPoint::twist_order(a, b);
+
+        // Frobenius satisfies t^2 - 4p = v^2 D for the fundamental D
+        let trace = modulus as i128 + 1 - group_order as i128;
+        let frobenius_discriminant = trace * trace - 4 * modulus as i128;
+
+        SecurityReport {
+            generator: *generator,
+            discriminant: -Felt::new(16, modulus)
+                * (Felt::new(4, modulus) * a.pow(3) + Felt::new(27, modulus) * b.pow(2)),
+            group_order,
+            generator_order,
+            largest_prime_factor: prime,
+            embedding_degree: embedding_degree(modulus, prime),
+            cm_discriminant: fundamental_discriminant(frobenius_discriminant),
+            twist_order,
+            twist_largest_prime_factor: largest_prime_factor(twist_order),
+            montgomery_form: MontgomeryPoint::montgomery_coefficients(a, b).is_ok(),
+            complete_edwards_form: complete_edwards_form(a, b),
+        }
+    }
+
+    pub fn generator(&self) -> ECPoint {
+        self.generator
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.generator.a().modulus()
+    }
+
+    // -16(4a^3 + 27b^2), nonzero for nonsingular curves
+    pub fn discriminant(&self) -> Felt {
+        self.discriminant
+    }
+
+    // #E
+    pub fn group_order(&self) -> u64 {
+        self.group_order
+    }
+
+    pub fn generator_order(&self) -> u64 {
+        self.generator_order
+    }
+
+    // Largest prime q dividing the generator's order, the discrete log is
+    // only as hard as in the subgroup of order q
+    pub fn largest_prime_factor(&self) -> u64 {
+        self.largest_prime_factor
+    }
+
+    // #E / q
+    pub fn cofactor(&self) -> u64 {
+        self.group_order / self.largest_prime_factor
+    }
+
+    pub fn is_prime_order_generator(&self) -> bool {
+        self.generator_order == self.largest_prime_factor
+    }
+
+    // Smallest k with q | p^k - 1, None when q = p
+    pub fn embedding_degree(&self) -> Option<u64> {
+        self.embedding_degree
+    }
+
+    // q = p, Smart's attack solves the discrete log in linear time
+    pub fn is_anomalous(&self) -> bool {
+        self.largest_prime_factor == self.modulus()
+    }
+
+    // Fundamental discriminant D of the endomorphism ring, with
+    // t^2 - 4p = v^2 D
+    pub fn cm_discriminant(&self) -> i128 {
+        self.cm_discriminant
+    }
+
+    pub fn twist_order(&self) -> u64 {
+        self.twist_order
+    }
+
+    pub fn twist_largest_prime_factor(&self) -> u64 {
+        self.twist_largest_prime_factor
+    }
+
+    // log2 of the expected number of additions of Pollard's rho,
+    // sqrt(pi q / 4)
+    pub fn rho_cost_bits(&self) -> f64 {
+        rho_cost_bits(self.largest_prime_factor)
+    }
+
+    pub fn twist_rho_cost_bits(&self) -> f64 {
+        rho_cost_bits(self.twist_largest_prime_factor)
+    }
+
+    // Whether the curve is birational to By^2 = x^3 + Ax^2 + x, whose
+    // x-only ladder has no exceptional cases
+    pub fn has_montgomery_form(&self) -> bool {
+        self.montgomery_form
+    }
+
+    // Twisted Edwards (a, d) with a square and d not, where the unified
+    // addition law is complete, if the curve has one
+    pub fn complete_edwards_form(&self) -> Option<(Felt, Felt)> {
+        self.complete_edwards_form
+    }
+
+    // Failed checks, empty if none
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if !self.is_prime_order_generator() {
+            warnings.push(format!(
+                "Generator order {} is not prime, use {} * G of order {}",
+                self.generator_order,
+                self.generator_order / self.largest_prime_factor,
+                self.largest_prime_factor
+            ));
+        }
+        if self.is_anomalous() {
+            warnings.push("Anomalous curve, Smart's attack applies".to_string());
+        }
+        if let Some(k) = self.embedding_degree.filter(|&k| k < MIN_EMBEDDING_DEGREE) {
+            warnings.push(format!(
+                "Embedding degree {} is low, the MOV attack maps the DLP to F_p^{}",
+                k, k
+            ));
+        }
+        if self.cm_discriminant.abs() <= 4 {
+            warnings.push(format!(
+                "CM discriminant {} gives extra automorphisms that speed up rho",
+                self.cm_discriminant
+            ));
+        }
+        if 2.0 * self.twist_rho_cost_bits() < self.rho_cost_bits() {
+            warnings.push(format!(
+                "Twist security 2^{:.1} is less than half the curve's 2^{:.1}",
+                self.twist_rho_cost_bits(),
+                self.rho_cost_bits()
+            ));
+        }
+        warnings
+    }
+}
+
+impl Display for SecurityReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (a, b) = (self.generator.a(), self.generator.b());
+        let yes_no = |value: bool| if value { "yes" } else { "no" };
+
+        writeln!(
+            f,
+            "Curve: y^2 = x^3 + {}x + {} (mod {})",
+            a.value(),
+            b.value(),
+            self.modulus()
+        )?;
+        writeln!(f, "Generator: {}", self.generator)?;
+        writeln!(f, "Discriminant: {}", self.discriminant.value())?;
+        writeln!(
+            f,
+            "Group order: {} = {}",
+            self.group_order,
+            factorization(self.group_order)
+        )?;
+        writeln!(f, "Generator order: {}", self.generator_order)?;
+        writeln!(
+            f,
+            "Largest prime factor: {}, cofactor {}",
+            self.largest_prime_factor,
+            self.cofactor()
+        )?;
+        writeln!(f, "Rho cost: 2^{:.1} additions", self.rho_cost_bits())?;
+        match self.embedding_degree {
+            Some(k) => writeln!(f, "Embedding degree: {}", k)?,
+            None => writeln!(f, "Embedding degree: none")?,
+        }
+        writeln!(f, "Anomalous: {}", yes_no(self.is_anomalous()))?;
+        writeln!(f, "CM discriminant: {}", self.cm_discriminant)?;
+        writeln!(
+            f,
+            "Twist order: {} = {}, rho cost 2^{:.1} additions",
+            self.twist_order,
+            factorization(self.twist_order),
+            self.twist_rho_cost_bits()
+        )?;
+        writeln!(f, "Montgomery form: {}", yes_no(self.montgomery_form))?;
+        match self.complete_edwards_form {
+            Some((a, d)) => writeln!(
+                f,
+                "Complete Edwards form: a = {}, d = {}",
+                a.value(),
+                d.value()
+            )?,
+            None => writeln!(f, "Complete Edwards form: none")?,
+        }
+
+        let warnings = self.warnings();
+        if warnings.is_empty() {
+            write!(f, "Warnings: none")
+        } else {
+            write!(f, "Warnings:")?;
+            for warning in warnings {
+                write!(f, "\n- {}", warning)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn rho_cost_bits(q: u64) -> f64 {
+    (PI * q as f64 / 4.0).sqrt().log2()
+}
+
+// D = -m or -4m for the squarefree part m of |d|, whichever is 0 or 1 mod 4
+fn fundamental_discriminant(d: i128) -> i128 {
+    let squarefree = squarefree_part(d.unsigned_abs()) as i128;
+    let sign = d.signum();
+    if (sign * squarefree).rem_euclid(4) == 1 {
+        sign * squarefree
+    } else {
+        4 * sign * squarefree
+    }
+}
+
+// |t^2 - 4p| <= 4p < 2^66 can overflow u64. Dividing out the primes below
+// 2^22 leaves a cofactor with at most two prime factors, which is squarefree
+// unless it is a square.
+fn squarefree_part(n: u128) -> u128 {
+    let mut n = n;
+    let mut squarefree = 1;
+    let mut d = 2;
+    while u64::try_from(n).is_err() && d < 1 << 22 {
+        let mut exponent = 0;
+        while n.is_multiple_of(d) {
+            n /= d;
+            exponent += 1;
+        }
+        if exponent % 2 == 1 {
+            squarefree *= d;
+        }
+        d += 1;
+    }
+
+    match u64::try_from(n) {
+        Ok(n) => {
+            squarefree
+                * prime_factors(n)
+                    .into_iter()
+                    .filter(|&(_, exponent)| exponent % 2 == 1)
+                    .map(|(p, _)| p as u128)
+                    .product::<u128>()
+        }
+        Err(_) if n.isqrt().pow(2) == n => squarefree,
+        Err(_) => squarefree * n,
+    }
+}
+
+// For each root alpha of x^3 + ax + b with 3alpha^2 + a = s^2, the curve is
+// By^2 = x^3 + Ax^2 + x with B = 1/s, A = 3alpha B, and twisted Edwards with
+// a = (A + 2) / B, d = (A - 2) / B
+fn complete_edwards_form(a: Felt, b: Felt) -> Option<(Felt, Felt)> {
+    let modulus = a.modulus();
+    let (felt_2, felt_3) = (Felt::new(2, modulus), Felt::new(3, modulus));
+
+    for alpha in (0..modulus).map(|alpha| Felt::new(alpha, modulus)) {
+        if (alpha.pow(3) + a * alpha + b).value() != 0 {
+            continue;
+        }
+        let Some(s) = (felt_3 * alpha.pow(2) + a).sqrt() else {
+            continue;
+        };
+        for s in [s, -s] {
+            let Ok(mont_b) = s.inverse() else {
+                continue;
+            };
+            let mont_a = felt_3 * alpha * mont_b;
+            let (edwards_a, edwards_d) = ((mont_a + felt_2) / mont_b, (mont_a - felt_2) / mont_b);
+            if edwards_a.value() != 0
+                && edwards_d.value() != 0
+                && EdwardsPoint::has_complete_addition(edwards_a, edwards_d)
+            {
+                return Some((edwards_a, edwards_d));
+            }
+        }
+    }
+
+    None
+}
+
+fn factorization(n: u64) -> String {
+    prime_factors(n)
+        .into_iter()
+        .map(|(p, exponent)| match exponent {
+            1 => p.to_string(),
+            _ => format!("{}^{}", p, exponent),
+        })
+        .collect::<Vec<_>>()
+        .join(" * ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn point(x: u64, y: u64, a: i64, b: i64, modulus: u64) -> ECPoint {
+        let felt = |v: i64| {
+            let f = Felt::new(v.unsigned_abs(), modulus);
+            if v < 0 {
+                -f
+            } else {
+                f
+            }
+        };
+        ECPoint::new(felt(x as i64), felt(y as i64), felt(a), felt(b)).unwrap()
+    }
+
+    #[test]
+    fn test_diffie_hellman_curve() {
+        let report = SecurityReport::analyze(&point(9, 2, 0, 6, 43));
+        assert_eq!(report.group_order(), 39);
+        assert_eq!(report.generator_order(), 39);
+        assert_eq!(report.largest_prime_factor(), 13);
+        assert_eq!(report.cofactor(), 3);
+        assert!(!report.is_prime_order_generator());
+        assert_eq!(report.embedding_degree(), Some(6));
+        assert!(!report.is_anomalous());
+        // t = 5 and t^2 - 4p = -147 = 7^2 * -3
+        assert_eq!(report.cm_discriminant(), -3);
+        assert_eq!(report.twist_order(), 49);
+        assert_eq!(report.twist_largest_prime_factor(), 7);
+        assert_eq!(report.warnings().len(), 3);
+
+        let text = report.to_string();
+        assert!(text.contains("Group order: 39 = 3 * 13"));
+        assert!(text.contains("Twist order: 49 = 7^2"));
+        assert!(text.contains("Embedding degree 6 is low"));
+    }
+
+    #[test]
+    fn test_prime_order_curve() {
+        let report = SecurityReport::analyze(&point(379, 1011, -3, -3, 1021));
+        assert_eq!(report.group_order(), 1039);
+        assert!(report.is_prime_order_generator());
+        assert_eq!(report.cofactor(), 1);
+        assert_eq!(report.embedding_degree(), Some(346));
+        // t = -17 and t^2 - 4p = -3795 is squarefree
+        assert_eq!(report.cm_discriminant(), -3795);
+        assert_eq!(report.twist_order(), 1005);
+        assert!((report.rho_cost_bits() - 4.83).abs() < 0.01);
+        // -16(4 * (-3)^3 + 27 * (-3)^2) = -2160
+        assert_eq!(report.discriminant().value(), 903);
+        assert!(report.warnings().is_empty());
+        assert!(report.to_string().ends_with("Warnings: none"));
+    }
+
+    #[test]
+    fn test_anomalous_curve() {
+        // y^2 = x^3 + x + 14 mod 43 has 43 points
+        let a = Felt::new(1, 43);
+        let b = Felt::new(14, 43);
+        let x = (0..43)
+            .map(|x| Felt::new(x, 43))
+            .find(|&x| {
+                (x.pow(3) + a * x + b)
+                    .sqrt()
+                    .is_some_and(|y| y.value() != 0)
+            })
+            .unwrap();
+        let g = ECPoint::new(x, (x.pow(3) + a * x + b).sqrt().unwrap(), a, b).unwrap();
+        let report = SecurityReport::analyze(&g);
+        assert!(report.is_anomalous());
+        assert_eq!(report.embedding_degree(), None);
+        assert!(report.to_string().contains("Anomalous: yes"));
+    }
+
+    #[test]
+    fn test_complete_edwards_form() {
+        // Weierstrass form of -x^2 + y^2 = 1 + 2x^2y^2 mod 101
+        let (mont_a, mont_b) =
+            EdwardsPoint::montgomery_coefficients(-Felt::new(1, 101), Felt::new(2, 101));
+        let (a, b) = MontgomeryPoint::weierstrass_coefficients(mont_a, mont_b);
+        let g = ECPoint::get_all_points(a, b)
+            .into_iter()
+            .find(|p| !p.is_infinity())
+            .unwrap();
+        let report = SecurityReport::analyze(&g);
+        assert!(report.has_montgomery_form());
+        let (edwards_a, edwards_d) = report.complete_edwards_form().unwrap();
+        assert!(EdwardsPoint::has_complete_addition(edwards_a, edwards_d));
+
+        // Prime order curves have no point of order 2 and so no Montgomery form
+        let report = SecurityReport::analyze(&point(379, 1011, -3, -3, 1021));
+        assert!(!report.has_montgomery_form());
+        assert_eq!(report.complete_edwards_form(), None);
+    }
+
+    #[test]
+    fn test_fundamental_discriminant() {
+        assert_eq!(fundamental_discriminant(-147), -3);
+        assert_eq!(fundamental_discriminant(-16), -4);
+        assert_eq!(fundamental_discriminant(-32), -8);
+        assert_eq!(fundamental_discriminant(-15), -15);
+        assert_eq!(fundamental_discriminant(-20), -20);
+
+        // |d| above 2^64, with q = 2^32 + 15 and r = 2^61 - 1 prime
+        let (q, r) = (4294967311_i128, 2305843009213693951_i128);
+        assert_eq!(fundamental_discriminant(-11 * r), -44 * r);
+        assert_eq!(fundamental_discriminant(-q * q), -4);
+        assert_eq!(fundamental_discriminant(-3 * q * q), -3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Generator must not be the point at infinity")]
+    fn test_infinity_should_panic() {
+        let g = point(379, 1011, -3, -3, 1021);
+        SecurityReport::analyze(&ECPoint::infinity(g.a(), g.b()));
+    }
+}
@@ -0,0 +1,126 @@
+use rand::Rng;
+
+use crate::felt::{felt::Felt, primes::prime_factors};
+
+use super::ec_point::ECPoint;
+
+// Subgroup of prime order q, where q is the largest prime dividing #E =
+// h * q. By Pohlig-Hellman a discrete log is only as hard as in the largest
+// prime order subgroup, so keys should live there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrimeSubgroup {
+    generator: ECPoint,
+    order: u64,
+    cofactor: u64,
+}
+
+impl PrimeSubgroup {
+    // Multiplies random points by #E / q^e until the result is not infinity,
+    // then by q while that stays nonzero, which leaves a point of order q.
+    // With e = 1 that is the usual cofactor multiplication. None if the curve
+    // only has the point at infinity.
+    pub fn find<R: Rng + ?Sized>(a: Felt, b: Felt, rng: &mut R) -> Option<Self> {
+        let count = ECPoint::count_points(a, b);
+        let (order, exponent) = *prime_factors(count).last()?;
+        let cofactor = count / order;
+        let other_part = count / order.pow(exponent);
+
+        loop {
+            let mut generator = random_point(a, b, rng) * other_part;
+            if generator.is_infinity() {
+                continue;
+            }
+            while !(generator * order).is_infinity() {
+                generator = generator * order;
+            }
+            return Some(PrimeSubgroup {
+                generator,
+                order,
+                cofactor,
+            });
+        }
+    }
+
+    pub fn generator(&self) -> ECPoint {
+        self.generator
+    }
+
+    // Prime q
+    pub fn order(&self) -> u64 {
+        self.order
+    }
+
+    // h = #E / q
+    pub fn cofactor(&self) -> u64 {
+        self.cofactor
+    }
+
+    // Whether point is on the same curve and q * point = 0. When q^2 divides
+    // #E there are several subgroups of order q and this accepts all of them.
+    pub fn contains(&self, point: &ECPoint) -> bool {
+        point.a() == self.generator.a()
+            && point.b() == self.generator.b()
+            && (*point * self.order).is_infinity()
+    }
+}
+
+// Uniform x until x^3 + ax + b is a square, then a random square root
+fn random_point<R: Rng + ?Sized>(a: Felt, b: Felt, rng: &mut R) -> ECPoint {
+    let modulus = a.modulus();
+    loop {
+        let x = Felt::new(rng.gen_range(0..modulus), modulus);
+        if let Some(y) = (x.pow(3) + a * x + b).sqrt() {
+            let y = if rng.gen() { y } else { -y };
+            return ECPoint::new(x, y, a, b).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_diffie_hellman_curve() {
+        // #E = 39 = 3 * 13
+        let mut rng = StdRng::seed_from_u64(43);
+        let a = Felt::new(0, 43);
+        let b = Felt::new(6, 43);
+        for _ in 0..10 {
+            let subgroup = PrimeSubgroup::find(a, b, &mut rng).unwrap();
+            assert_eq!(subgroup.order(), 13);
+            assert_eq!(subgroup.cofactor(), 3);
+            assert_eq!(subgroup.generator().order(), 13);
+        }
+    }
+
+    #[test]
+    fn test_prime_order_curve() {
+        let mut rng = StdRng::seed_from_u64(1021);
+        let a = -Felt::new(3, 1021);
+        let b = -Felt::new(3, 1021);
+        let subgroup = PrimeSubgroup::find(a, b, &mut rng).unwrap();
+        assert_eq!(subgroup.order(), 1039);
+        assert_eq!(subgroup.cofactor(), 1);
+        assert_eq!(subgroup.generator().order(), 1039);
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut rng = StdRng::seed_from_u64(61);
+        // y^2 = x^3 - x mod 61 is Z/6 x Z/12, so q = 3 and h = 24
+        let a = -Felt::new(1, 61);
+        let b = Felt::new(0, 61);
+        let subgroup = PrimeSubgroup::find(a, b, &mut rng).unwrap();
+        assert_eq!((subgroup.order(), subgroup.cofactor()), (3, 24));
+
+        let g = subgroup.generator();
+        assert!(subgroup.contains(&g));
+        assert!(subgroup.contains(&(g * 2)));
+        assert!(subgroup.contains(&ECPoint::infinity(a, b)));
+        // (0, 0) has order 2
+        let t = ECPoint::new(Felt::new(0, 61), Felt::new(0, 61), a, b).unwrap();
+        assert!(!subgroup.contains(&t));
+    }
+}
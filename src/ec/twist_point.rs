@@ -0,0 +1,194 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg},
+};
+
+use crate::felt::{big_fp2::BigFp2, big_uint::BigUint};
+
+use super::ec_errors::ECError;
+
+// Point on a sextic twist y^2 = x^3 + b over F_p^2, where the G2 group of BN
+// and BLS12 curves lives, see BigECPoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TwistPoint {
+    x: BigFp2,
+    y: BigFp2,
+    b: BigFp2,
+    infinity: bool,
+}
+
+impl TwistPoint {
+    pub fn new(x: BigFp2, y: BigFp2, b: BigFp2) -> Result<Self, ECError> {
+        if y * y != x * x * x + b {
+            return Err(ECError::TwistPointNotOnCurve(
+                x.to_string(),
+                y.to_string(),
+                b.to_string(),
+            ));
+        }
+        Ok(TwistPoint {
+            x,
+            y,
+            b,
+            infinity: false,
+        })
+    }
+
+    pub fn infinity(b: BigFp2) -> TwistPoint {
+        let zero = BigFp2::zero(b.modulus());
+        TwistPoint {
+            x: zero,
+            y: zero,
+            b,
+            infinity: true,
+        }
+    }
+
+    pub fn x(&self) -> BigFp2 {
+        self.x
+    }
+
+    pub fn y(&self) -> BigFp2 {
+        self.y
+    }
+
+    pub fn b(&self) -> BigFp2 {
+        self.b
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.infinity
+    }
+}
+
+impl Add for TwistPoint {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.b != other.b {
+            panic!("Points {}, {} are not on the same curve", self, other);
+        }
+
+        // P + 0 = P
+        if self.infinity {
+            return other;
+        }
+        if other.infinity {
+            return self;
+        }
+
+        // P + (-P) = 0
+        if self == -other {
+            return TwistPoint::infinity(self.b);
+        }
+
+        let s = if self == other {
+            let modulus = self.b.modulus();
+            let three = BigFp2::from_u64(3, 0, modulus);
+            (three * self.x * self.x) / (self.y + self.y)
+        } else {
+            (other.y - self.y) / (other.x - self.x)
+        };
+
+        let x = s * s - self.x - other.x;
+        let y = s * (self.x - x) - self.y;
+
+        TwistPoint {
+            x,
+            y,
+            b: self.b,
+            infinity: false,
+        }
+    }
+}
+
+impl AddAssign for TwistPoint {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Neg for TwistPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.infinity {
+            return self;
+        }
+        TwistPoint { y: -self.y, ..self }
+    }
+}
+
+impl Mul<BigUint> for TwistPoint {
+    type Output = Self;
+
+    fn mul(self, other: BigUint) -> Self {
+        let mut result = TwistPoint::infinity(self.b);
+        for i in (0..other.bits()).rev() {
+            result += result;
+            if other.bit(i) {
+                result += self;
+            }
+        }
+        result
+    }
+}
+
+impl Mul<TwistPoint> for BigUint {
+    type Output = TwistPoint;
+
+    fn mul(self, other: TwistPoint) -> TwistPoint {
+        other * self
+    }
+}
+
+impl Display for TwistPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.infinity {
+            write!(f, "Infinity")
+        } else {
+            write!(
+                f,
+                "({} + {}u, {} + {}u)",
+                self.x.c0().value(),
+                self.x.c1().value(),
+                self.y.c0().value(),
+                self.y.c1().value()
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fp2(c0: u64, c1: u64) -> BigFp2 {
+        BigFp2::from_u64(c0, c1, BigUint::from_u64(43))
+    }
+
+    #[test]
+    fn test_point_not_on_curve() {
+        assert!(TwistPoint::new(fp2(1, 1), fp2(1, 1), fp2(4, 4)).is_err());
+    }
+
+    // y^2 = x^3 + 4(1 + u) over F_43^2 has 1828 points, found by brute force
+    #[test]
+    fn test_multiplication() {
+        let g = TwistPoint::new(fp2(0, 6), fp2(35, 24), fp2(4, 4)).unwrap();
+        assert_eq!(
+            g + g,
+            TwistPoint::new(fp2(40, 1), fp2(30, 22), fp2(4, 4)).unwrap()
+        );
+        assert_eq!(g * BigUint::from_u64(2), g + g);
+        assert!((g * BigUint::from_u64(1828)).is_infinity());
+        assert_eq!(g * BigUint::from_u64(1827), -g);
+    }
+
+    #[test]
+    fn test_display() {
+        let g = TwistPoint::new(fp2(0, 6), fp2(35, 24), fp2(4, 4)).unwrap();
+        assert_eq!(g.to_string(), "(0x0 + 0x6u, 0x23 + 0x18u)");
+        assert_eq!(TwistPoint::infinity(fp2(4, 4)).to_string(), "Infinity");
+    }
+}
@@ -0,0 +1,468 @@
+use std::fmt::{Display, Formatter};
+
+use crate::felt::felt::Felt;
+
+use super::ec_errors::ECError;
+
+// Curve in long Weierstrass form
+// y^2 + a1xy + a3y = x^3 + a2x^2 + a4x + a6
+// Unlike ECPoint's y^2 = x^3 + ax + b this covers fields of characteristic
+// 2 and 3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeierstrassCurve {
+    a1: Felt,
+    a2: Felt,
+    a3: Felt,
+    a4: Felt,
+    a6: Felt,
+}
+
+// Admissible change of variables x = u^2x' + r, y = u^3y' + su^2x' + t
+// (Silverman, III.1), u must be non-zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariableChange {
+    pub u: Felt,
+    pub r: Felt,
+    pub s: Felt,
+    pub t: Felt,
+}
+
+impl WeierstrassCurve {
+    pub fn new(a1: Felt, a2: Felt, a3: Felt, a4: Felt, a6: Felt) -> Result<Self, ECError> {
+        let curve = WeierstrassCurve { a1, a2, a3, a4, a6 };
+        if curve.discriminant().value() == 0 {
+            return Err(ECError::SingularWeierstrassCurve(
+                a1.value(),
+                a2.value(),
+                a3.value(),
+                a4.value(),
+                a6.value(),
+            ));
+        }
+        Ok(curve)
+    }
+
+    // y^2 = x^3 + ax + b
+    pub fn from_short(a: Felt, b: Felt) -> Result<Self, ECError> {
+        let zero = Felt::new(0, a.modulus());
+        WeierstrassCurve::new(zero, zero, zero, a, b)
+    }
+
+    pub fn coefficients(&self) -> [Felt; 5] {
+        [self.a1, self.a2, self.a3, self.a4, self.a6]
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.a1.modulus()
+    }
+
+    pub fn contains(&self, x: Felt, y: Felt) -> bool {
+        let lhs = y.pow(2) + self.a1 * x * y + self.a3 * y;
+        let rhs = x.pow(3) + self.a2 * x.pow(2) + self.a4 * x + self.a6;
+        lhs == rhs
+    }
+
+    // (b2, b4, b6, b8)
+    pub fn b_invariants(&self) -> (Felt, Felt, Felt, Felt) {
+        let modulus = self.modulus();
+        let felt_2 = Felt::new(2, modulus);
+        let felt_4 = Felt::new(4, modulus);
+        let (a1, a2, a3, a4, a6) = (self.a1, self.a2, self.a3, self.a4, self.a6);
+
+        let b2 = a1.pow(2) + felt_4 * a2;
+        let b4 = felt_2 * a4 + a1 * a3;
+        let b6 = a3.pow(2) + felt_4 * a6;
+        let b8 = a1.pow(2) * a6 + felt_4 * a2 * a6 - a1 * a3 * a4 + a2 * a3.pow(2) - a4.pow(2);
+
+        (b2, b4, b6, b8)
+    }
+
+    // (c4, c6)
+    pub fn c_invariants(&self) -> (Felt, Felt) {
+        let modulus = self.modulus();
+        let felt_24 = Felt::new(24, modulus);
+        let felt_36 = Felt::new(36, modulus);
+        let felt_216 = Felt::new(216, modulus);
+        let (b2, b4, b6, _) = self.b_invariants();
+
+        let c4 = b2.pow(2) - felt_24 * b4;
+        let c6 = -b2.pow(3) + felt_36 * b2 * b4 - felt_216 * b6;
+
+        (c4, c6)
+    }
+
+    // Δ = -b2^2b8 - 8b4^3 - 27b6^2 + 9b2b4b6
+    pub fn discriminant(&self) -> Felt {
+        let modulus = self.modulus();
+        let felt_8 = Felt::new(8, modulus);
+        let felt_9 = Felt::new(9, modulus);
+        let felt_27 = Felt::new(27, modulus);
+        let (b2, b4, b6, b8) = self.b_invariants();
+
+        -b2.pow(2) * b8 - felt_8 * b4.pow(3) - felt_27 * b6.pow(2) + felt_9 * b2 * b4 * b6
+    }
+
+    // j = c4^3 / Δ
+    pub fn j_invariant(&self) -> Felt {
+        let (c4, _) = self.c_invariants();
+        c4.pow(3) / self.discriminant()
+    }
+
+    // Coefficients of the curve in the variables (x', y') of the change
+    pub fn change_variables(&self, change: VariableChange) -> WeierstrassCurve {
+        let modulus = self.modulus();
+        let felt_2 = Felt::new(2, modulus);
+        let felt_3 = Felt::new(3, modulus);
+        let (a1, a2, a3, a4, a6) = (self.a1, self.a2, self.a3, self.a4, self.a6);
+        let VariableChange { u, r, s, t } = change;
+
+        WeierstrassCurve {
+            a1: (a1 + felt_2 * s) / u,
+            a2: (a2 - s * a1 + felt_3 * r - s.pow(2)) / u.pow(2),
+            a3: (a3 + r * a1 + felt_2 * t) / u.pow(3),
+            a4: (a4 - s * a3 + felt_2 * r * a2 - (t + r * s) * a1 + felt_3 * r.pow(2)
+                - felt_2 * s * t)
+                / u.pow(4),
+            a6: (a6 + r * a4 + r.pow(2) * a2 + r.pow(3) - t * a3 - t.pow(2) - r * t * a1)
+                / u.pow(6),
+        }
+    }
+
+    // Change of variables taking the curve to y^2 = x^3 + ax + b, together
+    // with (a, b). Needs characteristic other than 2 and 3.
+    pub fn to_short_weierstrass(&self) -> Result<(VariableChange, Felt, Felt), ECError> {
+        let modulus = self.modulus();
+        if modulus == 2 || modulus == 3 {
+            return Err(ECError::NoShortWeierstrassForm(modulus));
+        }
+
+        let felt_2 = Felt::new(2, modulus);
+        let felt_12 = Felt::new(12, modulus);
+        let (b2, _, _, _) = self.b_invariants();
+
+        let s = -self.a1 / felt_2;
+        let r = -b2 / felt_12;
+        let t = -(self.a3 + r * self.a1) / felt_2;
+        let change = VariableChange {
+            u: Felt::new(1, modulus),
+            r,
+            s,
+            t,
+        };
+
+        let short = self.change_variables(change);
+        Ok((change, short.a4, short.a6))
+    }
+
+    // Change of variables mapping this curve onto other, if the curves are
+    // isomorphic over F_p. Needs characteristic other than 2 and 3.
+    pub fn isomorphism(&self, other: &WeierstrassCurve) -> Result<Option<VariableChange>, ECError> {
+        if self.modulus() != other.modulus() {
+            return Ok(None);
+        }
+        let (to_short, a1, b1) = self.to_short_weierstrass()?;
+        let (other_to_short, a2, b2) = other.to_short_weierstrass()?;
+
+        let scaling = match short_isomorphism(a1, b1, a2, b2) {
+            Some(u) => VariableChange {
+                u,
+                ..VariableChange::identity(self.modulus())
+            },
+            None => return Ok(None),
+        };
+
+        Ok(Some(
+            to_short.compose(scaling).compose(other_to_short.inverse()),
+        ))
+    }
+}
+
+impl VariableChange {
+    pub fn identity(modulus: u64) -> Self {
+        VariableChange {
+            u: Felt::new(1, modulus),
+            r: Felt::new(0, modulus),
+            s: Felt::new(0, modulus),
+            t: Felt::new(0, modulus),
+        }
+    }
+
+    // Applies self and then other
+    pub fn compose(&self, other: VariableChange) -> VariableChange {
+        let u2 = self.u.pow(2);
+        VariableChange {
+            u: self.u * other.u,
+            r: u2 * other.r + self.r,
+            s: self.u * other.s + self.s,
+            t: u2 * self.u * other.t + self.s * u2 * other.r + self.t,
+        }
+    }
+
+    pub fn inverse(&self) -> VariableChange {
+        let u_inv = Felt::new(1, self.u.modulus()) / self.u;
+        VariableChange {
+            u: u_inv,
+            r: -self.r * u_inv.pow(2),
+            s: -self.s * u_inv,
+            t: (self.r * self.s - self.t) * u_inv.pow(3),
+        }
+    }
+
+    // (x, y) -> (x', y'): x' = (x - r)/u^2, y' = (y - s(x - r) - t)/u^3
+    pub fn map_point(&self, x: Felt, y: Felt) -> (Felt, Felt) {
+        let new_x = (x - self.r) / self.u.pow(2);
+        let new_y = (y - self.s * (x - self.r) - self.t) / self.u.pow(3);
+        (new_x, new_y)
+    }
+
+    // (x', y') -> (x, y)
+    pub fn unmap_point(&self, x: Felt, y: Felt) -> (Felt, Felt) {
+        let u2 = self.u.pow(2);
+        let old_x = u2 * x + self.r;
+        let old_y = u2 * self.u * y + self.s * u2 * x + self.t;
+        (old_x, old_y)
+    }
+}
+
+// u such that x = u^2x', y = u^3y' maps y^2 = x^3 + a1x + b1 onto
+// y^2 = x^3 + a2x + b2, i.e. a2 = a1/u^4 and b2 = b1/u^6
+fn short_isomorphism(a1: Felt, b1: Felt, a2: Felt, b2: Felt) -> Option<Felt> {
+    let modulus = a1.modulus();
+    let one = Felt::new(1, modulus);
+    if (a1.value() == 0) != (a2.value() == 0) || (b1.value() == 0) != (b2.value() == 0) {
+        return None;
+    }
+
+    // j = 1728: u^4 = a1/a2
+    if b1.value() == 0 {
+        let root = (a1 / a2).sqrt()?;
+        return root.sqrt().or_else(|| (-root).sqrt());
+    }
+
+    // j = 0: u^6 = b1/b2, try every cube root of b1/b2
+    if a1.value() == 0 {
+        let root = (b1 / b2).cbrt()?;
+        let mut roots = vec![root];
+        if let Some(sqrt_minus_3) = (-Felt::new(3, modulus)).sqrt() {
+            if sqrt_minus_3.value() != 0 {
+                let omega = (sqrt_minus_3 - one) / Felt::new(2, modulus);
+                roots.push(root * omega);
+                roots.push(root * omega.pow(2));
+            }
+        }
+        return roots.into_iter().find_map(|root| root.sqrt());
+    }
+
+    // u^2 = (b1/b2) / (a1/a2), which also gives u^4 = a1/a2 when the
+    // j-invariants agree
+    let u = (b1 * a2 / (b2 * a1)).sqrt()?;
+    if u.pow(4) * a2 == a1 && u.pow(6) * b2 == b1 {
+        Some(u)
+    } else {
+        None
+    }
+}
+
+impl Display for WeierstrassCurve {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "y^2 + {}xy + {}y = x^3 + {}x^2 + {}x + {} (mod {})",
+            self.a1.value(),
+            self.a3.value(),
+            self.a2.value(),
+            self.a4.value(),
+            self.a6.value(),
+            self.modulus()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn curve(coefficients: [i64; 5], modulus: u64) -> Result<WeierstrassCurve, ECError> {
+        let [a1, a2, a3, a4, a6] = coefficients.map(|c| {
+            let f = Felt::new(c.unsigned_abs(), modulus);
+            if c < 0 {
+                -f
+            } else {
+                f
+            }
+        });
+        WeierstrassCurve::new(a1, a2, a3, a4, a6)
+    }
+
+    #[test]
+    fn test_singular_curve() {
+        // y^2 = x^3 has a cusp
+        assert!(curve([0, 0, 0, 0, 0], 101).is_err());
+    }
+
+    #[test]
+    fn test_invariants() {
+        // y^2 + y = x^3 - x^2 has Δ = -11 and j = -4096/11 over Q
+        let e = curve([0, -1, 1, 0, 0], 101).unwrap();
+        let (b2, b4, b6, b8) = e.b_invariants();
+        assert_eq!(b2, -Felt::new(4, 101));
+        assert_eq!(b4, Felt::new(0, 101));
+        assert_eq!(b6, Felt::new(1, 101));
+        assert_eq!(b8, -Felt::new(1, 101));
+        assert_eq!(e.c_invariants(), (Felt::new(16, 101), -Felt::new(152, 101)));
+        assert_eq!(e.discriminant(), -Felt::new(11, 101));
+        assert_eq!(e.j_invariant(), -Felt::new(4096, 101) / Felt::new(11, 101));
+    }
+
+    #[test]
+    fn test_short_curve_invariants() {
+        // Δ = -16(4a^3 + 27b^2), j = 1728 * 4a^3 / (4a^3 + 27b^2)
+        let a = Felt::new(2, 7);
+        let b = Felt::new(3, 7);
+        let e = WeierstrassCurve::from_short(a, b).unwrap();
+        let d = Felt::new(4, 7) * a.pow(3) + Felt::new(27, 7) * b.pow(2);
+        assert_eq!(e.discriminant(), -Felt::new(16, 7) * d);
+        assert_eq!(
+            e.j_invariant(),
+            Felt::new(1728, 7) * Felt::new(4, 7) * a.pow(3) / d
+        );
+    }
+
+    #[test]
+    fn test_characteristic_two() {
+        // y^2 + xy = x^3 + 1 over F_2
+        let e = curve([1, 0, 0, 0, 1], 2).unwrap();
+        assert_eq!(e.discriminant(), Felt::new(1, 2));
+        assert_eq!(e.j_invariant(), Felt::new(1, 2));
+        assert!(e.contains(Felt::new(0, 2), Felt::new(1, 2)));
+        assert!(e.to_short_weierstrass().is_err());
+    }
+
+    #[test]
+    fn test_characteristic_three() {
+        // y^2 = x^3 + x^2 + 1 over F_3
+        let e = curve([0, 1, 0, 0, 1], 3).unwrap();
+        assert_ne!(e.discriminant().value(), 0);
+        assert!(matches!(
+            e.to_short_weierstrass(),
+            Err(ECError::NoShortWeierstrassForm(3))
+        ));
+    }
+
+    #[test]
+    fn test_to_short_weierstrass() {
+        let e = curve([1, -1, 1, 3, 5], 101).unwrap();
+        let (change, a, b) = e.to_short_weierstrass().unwrap();
+        let short = e.change_variables(change);
+        let zero = Felt::new(0, 101);
+
+        assert_eq!(short.coefficients(), [zero, zero, zero, a, b]);
+        assert_eq!(short.j_invariant(), e.j_invariant());
+
+        let (c4, c6) = e.c_invariants();
+        assert_eq!(a, -c4 / Felt::new(48, 101));
+        assert_eq!(b, -c6 / Felt::new(864, 101));
+    }
+
+    #[test]
+    fn test_change_variables_maps_points() {
+        let e = curve([1, -1, 1, 3, 5], 101).unwrap();
+        let change = VariableChange {
+            u: Felt::new(3, 101),
+            r: Felt::new(7, 101),
+            s: Felt::new(11, 101),
+            t: Felt::new(13, 101),
+        };
+        let e2 = e.change_variables(change);
+
+        let mut count = 0;
+        for x in 0..101 {
+            for y in 0..101 {
+                let (x, y) = (Felt::new(x, 101), Felt::new(y, 101));
+                if e.contains(x, y) {
+                    let (x2, y2) = change.map_point(x, y);
+                    assert!(e2.contains(x2, y2));
+                    assert_eq!(change.unmap_point(x2, y2), (x, y));
+                    count += 1;
+                }
+            }
+        }
+        assert!(count > 0);
+    }
+
+    #[test]
+    fn test_compose_with_inverse_is_identity() {
+        let change = VariableChange {
+            u: Felt::new(3, 101),
+            r: Felt::new(7, 101),
+            s: Felt::new(11, 101),
+            t: Felt::new(13, 101),
+        };
+        assert_eq!(
+            change.compose(change.inverse()),
+            VariableChange::identity(101)
+        );
+        assert_eq!(
+            change.inverse().compose(change),
+            VariableChange::identity(101)
+        );
+    }
+
+    #[test]
+    fn test_isomorphism() {
+        let e = curve([1, -1, 1, 3, 5], 101).unwrap();
+        let change = VariableChange {
+            u: Felt::new(3, 101),
+            r: Felt::new(7, 101),
+            s: Felt::new(11, 101),
+            t: Felt::new(13, 101),
+        };
+        let e2 = e.change_variables(change);
+
+        let found = e.isomorphism(&e2).unwrap().unwrap();
+        assert_eq!(e.change_variables(found), e2);
+    }
+
+    #[test]
+    fn test_isomorphism_j_0_and_1728() {
+        // y^2 = x^3 + 6 and y^2 = x^3 + 6u^6 for p = 1 (mod 3)
+        let u = Felt::new(5, 43);
+        let e = curve([0, 0, 0, 0, 6], 43).unwrap();
+        let e2 =
+            WeierstrassCurve::from_short(Felt::new(0, 43), Felt::new(6, 43) / u.pow(6)).unwrap();
+        let found = e.isomorphism(&e2).unwrap().unwrap();
+        assert_eq!(e.change_variables(found), e2);
+
+        // y^2 = x^3 + x and y^2 = x^3 + x/u^4
+        let u = Felt::new(5, 101);
+        let e = curve([0, 0, 0, 1, 0], 101).unwrap();
+        let e2 =
+            WeierstrassCurve::from_short(Felt::new(1, 101) / u.pow(4), Felt::new(0, 101)).unwrap();
+        let found = e.isomorphism(&e2).unwrap().unwrap();
+        assert_eq!(e.change_variables(found), e2);
+    }
+
+    #[test]
+    fn test_twists_are_not_isomorphic() {
+        // Same j-invariant but a quadratic twist: 2 is not a square mod 101
+        let d = Felt::new(2, 101);
+        let e = curve([0, 0, 0, 3, 5], 101).unwrap();
+        let twist = WeierstrassCurve::from_short(
+            Felt::new(3, 101) * d.pow(2),
+            Felt::new(5, 101) * d.pow(3),
+        )
+        .unwrap();
+
+        assert_eq!(e.j_invariant(), twist.j_invariant());
+        assert_eq!(e.isomorphism(&twist).unwrap(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let e = curve([1, 0, 0, 0, 1], 2).unwrap();
+        assert_eq!(
+            format!("{}", e),
+            "y^2 + 1xy + 0y = x^3 + 0x^2 + 0x + 1 (mod 2)"
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg},
+};
+
+use crate::felt::felt::Felt;
+
+use super::{ec_errors::ECError, ec_point::ECPoint, weierstrass_curve::WeierstrassCurve};
+
+// Point on a curve in long Weierstrass form
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeierstrassPoint {
+    x: Felt,
+    y: Felt,
+    curve: WeierstrassCurve,
+    infinity: bool,
+}
+
+impl WeierstrassPoint {
+    pub fn new(x: Felt, y: Felt, curve: WeierstrassCurve) -> Result<Self, ECError> {
+        if !curve.contains(x, y) {
+            let [a1, a2, a3, a4, a6] = curve.coefficients().map(|a| a.value());
+            return Err(ECError::PointNotOnWeierstrassCurve(
+                x.value(),
+                y.value(),
+                a1,
+                a2,
+                a3,
+                a4,
+                a6,
+            ));
+        }
+        Ok(WeierstrassPoint {
+            x,
+            y,
+            curve,
+            infinity: false,
+        })
+    }
+
+    pub fn infinity(curve: WeierstrassCurve) -> WeierstrassPoint {
+        WeierstrassPoint {
+            x: Felt::new(0, curve.modulus()),
+            y: Felt::new(0, curve.modulus()),
+            curve,
+            infinity: true,
+        }
+    }
+
+    pub fn x(&self) -> Felt {
+        self.x
+    }
+
+    pub fn y(&self) -> Felt {
+        self.y
+    }
+
+    pub fn curve(&self) -> WeierstrassCurve {
+        self.curve
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.infinity
+    }
+
+    // Image of the point on the isomorphic short Weierstrass curve
+    pub fn to_short_weierstrass(&self) -> Result<ECPoint, ECError> {
+        let (change, a, b) = self.curve.to_short_weierstrass()?;
+        if self.infinity {
+            return Ok(ECPoint::infinity(a, b));
+        }
+        let (x, y) = change.map_point(self.x, self.y);
+        ECPoint::new(x, y, a, b)
+    }
+
+    // Naive implementation of getting all points on the curve
+    pub fn get_all_points(curve: WeierstrassCurve) -> Vec<WeierstrassPoint> {
+        let modulus = curve.modulus();
+        let mut points = vec![WeierstrassPoint::infinity(curve)];
+        for x in 0..modulus {
+            for y in 0..modulus {
+                if let Ok(point) =
+                    WeierstrassPoint::new(Felt::new(x, modulus), Felt::new(y, modulus), curve)
+                {
+                    points.push(point);
+                }
+            }
+        }
+        points
+    }
+}
+
+impl Add for WeierstrassPoint {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.curve != other.curve {
+            panic!("Points {}, {} are not on the same curve", self, other);
+        }
+
+        // P + 0 = P
+        if self.infinity {
+            return other;
+        }
+        if other.infinity {
+            return self;
+        }
+
+        // P + (-P) = 0
+        if self == -other {
+            return WeierstrassPoint::infinity(self.curve);
+        }
+
+        let modulus = self.curve.modulus();
+        let [a1, a2, a3, a4, a6] = self.curve.coefficients();
+
+        // The line through both points is y = sx + v
+        let (s, v) = if self == other {
+            let felt_2 = Felt::new(2, modulus);
+            let felt_3 = Felt::new(3, modulus);
+            let denominator = felt_2 * self.y + a1 * self.x + a3;
+
+            let s =
+                (felt_3 * self.x.pow(2) + felt_2 * a2 * self.x + a4 - a1 * self.y) / denominator;
+            let v = (-self.x.pow(3) + a4 * self.x + felt_2 * a6 - a3 * self.y) / denominator;
+            (s, v)
+        } else {
+            let denominator = other.x - self.x;
+
+            let s = (other.y - self.y) / denominator;
+            let v = (self.y * other.x - other.y * self.x) / denominator;
+            (s, v)
+        };
+
+        let x = s.pow(2) + a1 * s - a2 - self.x - other.x;
+        let y = -(s + a1) * x - v - a3;
+
+        WeierstrassPoint::new(x, y, self.curve).unwrap()
+    }
+}
+
+impl AddAssign for WeierstrassPoint {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Neg for WeierstrassPoint {
+    type Output = Self;
+
+    // -(x, y) = (x, -y - a1x - a3)
+    fn neg(self) -> Self {
+        if self.infinity {
+            return self;
+        }
+        let [a1, _, a3, _, _] = self.curve.coefficients();
+        WeierstrassPoint::new(self.x, -self.y - a1 * self.x - a3, self.curve).unwrap()
+    }
+}
+
+impl Mul<u64> for WeierstrassPoint {
+    type Output = Self;
+
+    fn mul(self, other: u64) -> Self {
+        let mut result = WeierstrassPoint::infinity(self.curve);
+        let mut current = self;
+        let mut i = other;
+
+        while i > 0 {
+            if i % 2 == 1 {
+                result += current;
+            }
+            i >>= 1;
+            current += current;
+        }
+
+        result
+    }
+}
+
+impl Mul<WeierstrassPoint> for u64 {
+    type Output = WeierstrassPoint;
+
+    fn mul(self, other: WeierstrassPoint) -> WeierstrassPoint {
+        other * self
+    }
+}
+
+impl Display for WeierstrassPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.infinity {
+            write!(f, "Infinity")
+        } else {
+            write!(f, "({}, {})", self.x.value(), self.y.value())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn felt(value: i64, modulus: u64) -> Felt {
+        let f = Felt::new(value.unsigned_abs(), modulus);
+        if value < 0 {
+            -f
+        } else {
+            f
+        }
+    }
+
+    fn curve(coefficients: [i64; 5], modulus: u64) -> WeierstrassCurve {
+        let [a1, a2, a3, a4, a6] = coefficients.map(|c| felt(c, modulus));
+        WeierstrassCurve::new(a1, a2, a3, a4, a6).unwrap()
+    }
+
+    #[test]
+    fn test_point_not_on_curve() {
+        let e = curve([1, 0, 0, 0, 1], 2);
+        assert!(WeierstrassPoint::new(Felt::new(0, 2), Felt::new(0, 2), e).is_err());
+    }
+
+    #[test]
+    fn test_characteristic_two_group() {
+        // y^2 + xy = x^3 + 1 over F_2 has 4 points: 0, (0, 1), (1, 0), (1, 1)
+        let e = curve([1, 0, 0, 0, 1], 2);
+        let points = WeierstrassPoint::get_all_points(e);
+        assert_eq!(points.len(), 4);
+
+        let p = WeierstrassPoint::new(Felt::new(1, 2), Felt::new(0, 2), e).unwrap();
+        let q = WeierstrassPoint::new(Felt::new(0, 2), Felt::new(1, 2), e).unwrap();
+        assert_eq!(p + p, q);
+        assert_eq!(
+            -p,
+            WeierstrassPoint::new(Felt::new(1, 2), Felt::new(1, 2), e).unwrap()
+        );
+        assert!((4 * p).is_infinity());
+    }
+
+    #[test]
+    fn test_group_law_is_associative() {
+        for e in [
+            curve([1, 0, 0, 0, 1], 2),
+            curve([0, 1, 0, 0, 1], 3),
+            curve([1, -1, 1, 3, 5], 23),
+        ] {
+            let points = WeierstrassPoint::get_all_points(e);
+            for &p in points.iter() {
+                assert!((p + (-p)).is_infinity());
+                for &q in points.iter().take(6) {
+                    assert_eq!(p + q, q + p);
+                    for &r in points.iter().take(6) {
+                        assert_eq!((p + q) + r, p + (q + r));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiplication_matches_addition() {
+        let e = curve([0, -1, 1, 0, 0], 101);
+        let p = WeierstrassPoint::new(Felt::new(0, 101), Felt::new(0, 101), e).unwrap();
+
+        let mut p_add = WeierstrassPoint::infinity(e);
+        for i in 1..50 {
+            p_add += p;
+            assert_eq!(p_add, i * p);
+        }
+        // (0, 0) generates the torsion subgroup of order 5 over Q
+        assert!((5 * p).is_infinity());
+    }
+
+    #[test]
+    fn test_to_short_weierstrass_preserves_addition() {
+        let e = curve([1, -1, 1, 3, 5], 101);
+        let points = WeierstrassPoint::get_all_points(e);
+        let p = points[1];
+        let q = points[points.len() / 2];
+
+        let p_short = p.to_short_weierstrass().unwrap();
+        let q_short = q.to_short_weierstrass().unwrap();
+        assert_eq!((p + q).to_short_weierstrass().unwrap(), p_short + q_short);
+        assert_eq!((p + p).to_short_weierstrass().unwrap(), p_short + p_short);
+        assert_eq!(
+            ECPoint::get_all_points(p_short.a(), p_short.b()).len(),
+            points.len()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let e = curve([1, 0, 0, 0, 1], 2);
+        let p = WeierstrassPoint::new(Felt::new(1, 2), Felt::new(1, 2), e).unwrap();
+        assert_eq!(format!("{}", p), "(1, 1)");
+        assert_eq!(format!("{}", WeierstrassPoint::infinity(e)), "Infinity");
+    }
+}
@@ -0,0 +1,126 @@
+use crate::felt::fe25519::Fe25519;
+
+// u-coordinate of the Curve25519 base point
+pub const X25519_BASEPOINT: [u8; 32] = [
+    9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+// (A - 2) / 4 for Curve25519, A = 486662
+const A24: u64 = 121665;
+
+// X25519 as specified in RFC 7748: the Montgomery ladder on
+// y^2 = x^3 + 486662x^2 + x (mod 2^255 - 19) with a clamped scalar. The
+// ladder swaps without branching on scalar bits, but Fe25519 arithmetic has
+// data-dependent carry loops, so this is not hardened against timing side
+// channels.
+pub fn x25519(scalar: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    let k = clamp(scalar);
+    let x_1 = Fe25519::from_bytes(&u);
+
+    ladder(&k, x_1).to_bytes()
+}
+
+fn clamp(mut scalar: [u8; 32]) -> [u8; 32] {
+    scalar[0] &= 248;
+    scalar[31] &= 127;
+    scalar[31] |= 64;
+    scalar
+}
+
+// x(k*P) from x(P), k is a little-endian 255-bit integer
+fn ladder(k: &[u8; 32], x_1: Fe25519) -> Fe25519 {
+    let a24 = Fe25519::from_u64(A24);
+    let mut x_2 = Fe25519::one();
+    let mut z_2 = Fe25519::zero();
+    let mut x_3 = x_1;
+    let mut z_3 = Fe25519::one();
+
+    // RFC 7748 merges the swaps back and forth into one per bit
+    let mut swap = 0;
+    for t in (0..255).rev() {
+        let k_t = ((k[t / 8] >> (t % 8)) & 1) as u64;
+        swap ^= k_t;
+        Fe25519::cswap(&mut x_2, &mut x_3, swap);
+        Fe25519::cswap(&mut z_2, &mut z_3, swap);
+        swap = k_t;
+
+        let a = x_2 + z_2;
+        let aa = a.square();
+        let b = x_2 - z_2;
+        let bb = b.square();
+        let e = aa - bb;
+        let c = x_3 + z_3;
+        let d = x_3 - z_3;
+        let da = d * a;
+        let cb = c * b;
+        x_3 = (da + cb).square();
+        z_3 = x_1 * (da - cb).square();
+        x_2 = aa * bb;
+        z_2 = e * (aa + a24 * e);
+    }
+    Fe25519::cswap(&mut x_2, &mut x_3, swap);
+    Fe25519::cswap(&mut z_2, &mut z_3, swap);
+
+    x_2 * z_2.invert()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn from_hex(hex: &str) -> [u8; 32] {
+        let mut bytes = [0_u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    // RFC 7748, section 5.2
+    #[test]
+    fn test_x25519_vector_1() {
+        let scalar = from_hex("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+        let u = from_hex("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c");
+        let expected = from_hex("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552");
+        assert_eq!(x25519(scalar, u), expected);
+    }
+
+    // RFC 7748, section 5.2. The most significant bit of u must be ignored.
+    #[test]
+    fn test_x25519_vector_2() {
+        let scalar = from_hex("4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d");
+        let u = from_hex("e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493");
+        let expected = from_hex("95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957");
+        assert_eq!(x25519(scalar, u), expected);
+    }
+
+    // RFC 7748, section 5.2, after one iteration
+    #[test]
+    fn test_x25519_one_iteration() {
+        let expected = from_hex("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079");
+        assert_eq!(x25519(X25519_BASEPOINT, X25519_BASEPOINT), expected);
+    }
+
+    // RFC 7748, section 6.1
+    #[test]
+    fn test_x25519_diffie_hellman() {
+        let alice_sk = from_hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob_sk = from_hex("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+
+        let alice_public = x25519(alice_sk, X25519_BASEPOINT);
+        let bob_public = x25519(bob_sk, X25519_BASEPOINT);
+        assert_eq!(
+            alice_public,
+            from_hex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public,
+            from_hex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+
+        let shared_secret =
+            from_hex("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!(x25519(alice_sk, bob_public), shared_secret);
+        assert_eq!(x25519(bob_sk, alice_public), shared_secret);
+    }
+}
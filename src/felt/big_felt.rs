@@ -0,0 +1,207 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::{big_uint::BigUint, felt_errors::FeltError};
+
+// Field element for moduli that do not fit in a u64, see Felt
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct BigFelt {
+    value: BigUint,
+    modulus: BigUint,
+}
+
+impl BigFelt {
+    pub fn new(value: BigUint, modulus: BigUint) -> Self {
+        BigFelt {
+            value: value.rem(&modulus),
+            modulus,
+        }
+    }
+
+    pub fn from_u64(value: u64, modulus: BigUint) -> Self {
+        BigFelt::new(BigUint::from_u64(value), modulus)
+    }
+
+    // Fermat's little theorem, the modulus must be a prime
+    pub fn inverse(&self) -> Result<Self, FeltError> {
+        if self.value.is_zero() {
+            return Err(FeltError::BigNotInvertible(self.value, self.modulus));
+        }
+        Ok(self.pow(&(self.modulus - BigUint::from_u64(2))))
+    }
+
+    pub fn pow(&self, exponent: &BigUint) -> Self {
+        let mut result = BigFelt::from_u64(1, self.modulus);
+        for i in (0..exponent.bits()).rev() {
+            result = result * result;
+            if exponent.bit(i) {
+                result = result * *self;
+            }
+        }
+        result
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    pub fn value(&self) -> BigUint {
+        self.value
+    }
+
+    pub fn modulus(&self) -> BigUint {
+        self.modulus
+    }
+}
+
+impl Add for BigFelt {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.modulus != other.modulus {
+            panic!("Cannot add two BigFelt values with different moduli");
+        }
+        let (sum, carry) = self.value.overflowing_add(&other.value);
+        if carry || sum >= self.modulus {
+            let (value, _) = sum.overflowing_sub(&self.modulus);
+            return BigFelt {
+                value,
+                modulus: self.modulus,
+            };
+        }
+        BigFelt {
+            value: sum,
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Sub for BigFelt {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        if self.modulus != other.modulus {
+            panic!("Cannot subtract two BigFelt values with different moduli");
+        }
+        let (diff, borrow) = self.value.overflowing_sub(&other.value);
+        let value = if borrow {
+            diff.overflowing_add(&self.modulus).0
+        } else {
+            diff
+        };
+        BigFelt {
+            value,
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Mul for BigFelt {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        if self.modulus != other.modulus {
+            panic!("Cannot multiply two BigFelt values with different moduli");
+        }
+        BigFelt {
+            value: self.value.mul_mod(&other.value, &self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Div for BigFelt {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        if self.modulus != other.modulus {
+            panic!("Cannot divide two BigFelt values with different moduli");
+        }
+        if other.value.is_zero() {
+            panic!("Cannot divide by zero");
+        }
+        match other.inverse() {
+            Ok(inverse) => self * inverse,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+impl Neg for BigFelt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        BigFelt::new(self.modulus - self.value, self.modulus)
+    }
+}
+
+impl std::fmt::Display for BigFelt {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.modulus)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn p256() -> BigUint {
+        BigUint::from_hex("0xffffffff00000001000000000000000000000000ffffffffffffffffffffffff")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_new_reduces_value() {
+        let p = p256();
+        let f = BigFelt::new(p + BigUint::from_u64(5), p);
+        assert_eq!(f.value(), BigUint::from_u64(5));
+    }
+
+    #[test]
+    fn test_add_and_sub_wrap_around() {
+        let p = p256();
+        let minus_one = -BigFelt::from_u64(1, p);
+        assert_eq!(minus_one.value(), p - BigUint::one());
+        assert!((minus_one + BigFelt::from_u64(1, p)).is_zero());
+        assert_eq!(BigFelt::from_u64(2, p) - BigFelt::from_u64(3, p), minus_one);
+    }
+
+    #[test]
+    fn test_add_with_carry_out_of_top_limb() {
+        let p = BigUint::from_hex(&format!("{}45", "f".repeat(94))).unwrap();
+        let x = BigFelt::new(p - BigUint::one(), p);
+        assert_eq!((x + x).value(), p - BigUint::from_u64(2));
+    }
+
+    #[test]
+    fn test_multiply_with_inverse_should_equal_one() {
+        let p = p256();
+        let f = BigFelt::new(BigUint::from_hex("0x123456789abcdef").unwrap(), p);
+        assert_eq!(f * f.inverse().unwrap(), BigFelt::from_u64(1, p));
+        assert_eq!(f / f, BigFelt::from_u64(1, p));
+    }
+
+    #[test]
+    fn test_inverse_of_zero() {
+        assert!(BigFelt::from_u64(0, p256()).inverse().is_err());
+    }
+
+    #[test]
+    fn test_pow_matches_fermat() {
+        let p = p256();
+        let f = BigFelt::from_u64(3, p);
+        assert_eq!(f.pow(&(p - BigUint::one())), BigFelt::from_u64(1, p));
+        assert_eq!(f.pow(&BigUint::from_u64(5)), BigFelt::from_u64(243, p));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot add two BigFelt values with different moduli")]
+    fn test_add_with_different_modulus_should_panic() {
+        let _ = BigFelt::from_u64(1, p256()) + BigFelt::from_u64(1, BigUint::from_u64(7));
+    }
+
+    #[test]
+    fn test_display() {
+        let f = BigFelt::from_u64(255, BigUint::from_u64(257));
+        assert_eq!(format!("{}", f), "0xff (mod 0x101)");
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::{big_felt::BigFelt, big_uint::BigUint, felt_errors::FeltError};
+
+// Element c0 + c1 u of F_p^2 = F_p[u] / (u^2 + 1) for a big prime p = 3
+// (mod 4), the quadratic extension used by BN254 and BLS12-381. See Fp2 for
+// 64-bit fields with any non-residue.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct BigFp2 {
+    c0: BigFelt,
+    c1: BigFelt,
+}
+
+impl BigFp2 {
+    pub fn new(c0: BigFelt, c1: BigFelt) -> Self {
+        if c0.modulus() != c1.modulus() {
+            panic!("Cannot build a BigFp2 value from different moduli");
+        }
+        if c0.modulus().limbs()[0] & 3 != 3 {
+            panic!("-1 must be a non-residue, the modulus must be 3 (mod 4)");
+        }
+        BigFp2 { c0, c1 }
+    }
+
+    pub fn from_u64(c0: u64, c1: u64, modulus: BigUint) -> Self {
+        BigFp2::new(
+            BigFelt::from_u64(c0, modulus),
+            BigFelt::from_u64(c1, modulus),
+        )
+    }
+
+    pub fn from_big_felt(value: BigFelt) -> Self {
+        BigFp2::new(value, BigFelt::from_u64(0, value.modulus()))
+    }
+
+    pub fn zero(modulus: BigUint) -> Self {
+        BigFp2::from_u64(0, 0, modulus)
+    }
+
+    pub fn one(modulus: BigUint) -> Self {
+        BigFp2::from_u64(1, 0, modulus)
+    }
+
+    pub fn c0(&self) -> BigFelt {
+        self.c0
+    }
+
+    pub fn c1(&self) -> BigFelt {
+        self.c1
+    }
+
+    pub fn modulus(&self) -> BigUint {
+        self.c0.modulus()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    // N(x) = x * conj(x) = c0^2 + c1^2, in F_p
+    pub fn norm(&self) -> BigFelt {
+        self.c0 * self.c0 + self.c1 * self.c1
+    }
+
+    // x^p = c0 - c1 u since u^p = -u
+    pub fn conjugate(&self) -> Self {
+        BigFp2 {
+            c0: self.c0,
+            c1: -self.c1,
+        }
+    }
+
+    // conj(x) / N(x)
+    pub fn inverse(&self) -> Result<Self, FeltError> {
+        let norm_inverse = self.norm().inverse()?;
+        Ok(BigFp2 {
+            c0: self.c0 * norm_inverse,
+            c1: -self.c1 * norm_inverse,
+        })
+    }
+
+    pub fn pow(&self, exponent: &BigUint) -> Self {
+        let mut result = BigFp2::one(self.modulus());
+        for i in (0..exponent.bits()).rev() {
+            result = result * result;
+            if exponent.bit(i) {
+                result = result * *self;
+            }
+        }
+        result
+    }
+}
+
+impl Add for BigFp2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        BigFp2 {
+            c0: self.c0 + other.c0,
+            c1: self.c1 + other.c1,
+        }
+    }
+}
+
+impl Sub for BigFp2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        BigFp2 {
+            c0: self.c0 - other.c0,
+            c1: self.c1 - other.c1,
+        }
+    }
+}
+
+impl Mul for BigFp2 {
+    type Output = Self;
+
+    // Karatsuba, (a0 + a1 u)(b0 + b1 u) = a0 b0 - a1 b1 + (a0 b1 + a1 b0) u
+    fn mul(self, other: Self) -> Self {
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        BigFp2 {
+            c0: v0 - v1,
+            c1: (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1,
+        }
+    }
+}
+
+impl Mul<BigFelt> for BigFp2 {
+    type Output = Self;
+
+    fn mul(self, other: BigFelt) -> Self {
+        BigFp2 {
+            c0: self.c0 * other,
+            c1: self.c1 * other,
+        }
+    }
+}
+
+impl Div for BigFp2 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        match other.inverse() {
+            Ok(inverse) => self.mul(inverse),
+            Err(_) => panic!("Cannot divide by zero"),
+        }
+    }
+}
+
+impl Neg for BigFp2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        BigFp2 {
+            c0: -self.c0,
+            c1: -self.c1,
+        }
+    }
+}
+
+impl Display for BigFp2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} + {}u (mod {})",
+            self.c0.value(),
+            self.c1.value(),
+            self.modulus()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bn254() -> BigUint {
+        BigUint::from_hex("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let p = bn254();
+        let x = BigFp2::from_u64(3, 5, p);
+        let y = BigFp2::from_u64(7, 2, p);
+        // (3 + 5u)(7 + 2u) = 21 - 10 + (6 + 35)u
+        assert_eq!(x * y, BigFp2::from_u64(11, 41, p));
+        assert_eq!(x + y, BigFp2::from_u64(10, 7, p));
+        assert_eq!(y - x + x, y);
+        assert_eq!(x / y * y, x);
+        assert_eq!(x * x.inverse().unwrap(), BigFp2::one(p));
+        assert!((x + -x).is_zero());
+    }
+
+    #[test]
+    fn test_conjugate_is_pth_power() {
+        let p = bn254();
+        let x = BigFp2::from_u64(3, 5, p);
+        assert_eq!(x.pow(&p), x.conjugate());
+        assert_eq!(BigFp2::from_big_felt(x.norm()), x * x.conjugate());
+    }
+
+    #[test]
+    fn test_inverse_of_zero() {
+        assert!(BigFp2::zero(bn254()).inverse().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "-1 must be a non-residue, the modulus must be 3 (mod 4)")]
+    fn test_modulus_one_mod_four_should_panic() {
+        BigFp2::from_u64(1, 1, BigUint::from_u64(13));
+    }
+
+    #[test]
+    fn test_display() {
+        let x = BigFp2::from_u64(3, 255, BigUint::from_u64(263));
+        assert_eq!(x.to_string(), "0x3 + 0xffu (mod 0x107)");
+    }
+}
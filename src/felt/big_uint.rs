@@ -0,0 +1,478 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    ops::{Add, Mul, Sub},
+};
+
+use super::felt_errors::FeltError;
+
+// Enough for 384-bit moduli (P-384, BLS12-381)
+pub const LIMBS: usize = 6;
+
+// Fixed-width unsigned integer, little-endian 64-bit limbs. Arithmetic panics
+// on overflow, modular arithmetic lives in BigFelt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: [u64; LIMBS],
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint { limbs: [0; LIMBS] }
+    }
+
+    pub fn one() -> Self {
+        BigUint::from_u64(1)
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = value;
+        BigUint { limbs }
+    }
+
+    pub fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        BigUint { limbs }
+    }
+
+    pub fn limbs(&self) -> [u64; LIMBS] {
+        self.limbs
+    }
+
+    // Accepts an optional 0x prefix, underscores are ignored
+    pub fn from_hex(hex: &str) -> Result<Self, FeltError> {
+        let digits = hex.strip_prefix("0x").unwrap_or(hex);
+        let mut result = BigUint::zero();
+        let mut any_digit = false;
+
+        for c in digits.chars().filter(|&c| c != '_') {
+            let digit = c
+                .to_digit(16)
+                .ok_or_else(|| FeltError::InvalidHex(hex.to_string()))?;
+            if result.bits() > 64 * LIMBS as u32 - 4 {
+                return Err(FeltError::InvalidHex(hex.to_string()));
+            }
+            result = result.shl(4) + BigUint::from_u64(digit as u64);
+            any_digit = true;
+        }
+
+        if !any_digit {
+            return Err(FeltError::InvalidHex(hex.to_string()));
+        }
+        Ok(result)
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        if self.limbs[1..].iter().all(|&limb| limb == 0) {
+            Some(self.limbs[0])
+        } else {
+            None
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&limb| limb == 0)
+    }
+
+    // Number of significant bits
+    pub fn bits(&self) -> u32 {
+        for i in (0..LIMBS).rev() {
+            if self.limbs[i] != 0 {
+                return 64 * i as u32 + 64 - self.limbs[i].leading_zeros();
+            }
+        }
+        0
+    }
+
+    pub fn bit(&self, i: u32) -> bool {
+        let limb = (i / 64) as usize;
+        limb < LIMBS && (self.limbs[limb] >> (i % 64)) & 1 == 1
+    }
+
+    pub fn is_even(&self) -> bool {
+        !self.bit(0)
+    }
+
+    pub fn shl(&self, shift: u32) -> Self {
+        let mut limbs = [0; LIMBS];
+        let words = (shift / 64) as usize;
+        let bits = shift % 64;
+        for i in (words..LIMBS).rev() {
+            limbs[i] = self.limbs[i - words] << bits;
+            if bits > 0 && i > words {
+                limbs[i] |= self.limbs[i - words - 1] >> (64 - bits);
+            }
+        }
+        BigUint { limbs }
+    }
+
+    pub fn shr(&self, shift: u32) -> Self {
+        let mut limbs = [0; LIMBS];
+        let words = (shift / 64) as usize;
+        let bits = shift % 64;
+        for (i, limb) in limbs
+            .iter_mut()
+            .take(LIMBS.saturating_sub(words))
+            .enumerate()
+        {
+            *limb = self.limbs[i + words] >> bits;
+            if bits > 0 && i + words + 1 < LIMBS {
+                *limb |= self.limbs[i + words + 1] << (64 - bits);
+            }
+        }
+        BigUint { limbs }
+    }
+
+    // (self * other) mod modulus
+    pub fn mul_mod(&self, other: &BigUint, modulus: &BigUint) -> BigUint {
+        let mut wide = [0_u64; 2 * LIMBS];
+        for i in 0..LIMBS {
+            let mut carry = 0_u128;
+            for j in 0..LIMBS {
+                let acc =
+                    wide[i + j] as u128 + self.limbs[i] as u128 * other.limbs[j] as u128 + carry;
+                wide[i + j] = acc as u64;
+                carry = acc >> 64;
+            }
+            wide[i + LIMBS] = carry as u64;
+        }
+        rem_wide(&wide, modulus)
+    }
+
+    pub fn rem(&self, modulus: &BigUint) -> BigUint {
+        rem_wide(&self.limbs, modulus)
+    }
+
+    // Quotient and remainder by a small divisor, schoolbook long division
+    pub fn div_rem_u64(&self, divisor: u64) -> (BigUint, u64) {
+        if divisor == 0 {
+            panic!("Cannot divide by zero");
+        }
+        let mut limbs = [0; LIMBS];
+        let mut remainder = 0_u128;
+        for i in (0..LIMBS).rev() {
+            let acc = (remainder << 64) | self.limbs[i] as u128;
+            limbs[i] = (acc / divisor as u128) as u64;
+            remainder = acc % divisor as u128;
+        }
+        (BigUint { limbs }, remainder as u64)
+    }
+
+    pub fn overflowing_add(&self, other: &BigUint) -> (BigUint, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut carry = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (sum, c1) = self.limbs[i].overflowing_add(other.limbs[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = c1 || c2;
+        }
+        (BigUint { limbs }, carry)
+    }
+
+    pub fn overflowing_sub(&self, other: &BigUint) -> (BigUint, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (diff, b1) = self.limbs[i].overflowing_sub(other.limbs[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u64);
+            *limb = diff;
+            borrow = b1 || b2;
+        }
+        (BigUint { limbs }, borrow)
+    }
+}
+
+// Remainder of a little-endian number by modulus, Knuth's algorithm D
+// (The Art of Computer Programming, Vol. 2, 4.3.1)
+fn rem_wide(numerator: &[u64], modulus: &BigUint) -> BigUint {
+    let v = &modulus.limbs;
+    let n = match v.iter().rposition(|&limb| limb != 0) {
+        Some(top) => top + 1,
+        None => panic!("Cannot divide by zero"),
+    };
+    let m = numerator
+        .iter()
+        .rposition(|&limb| limb != 0)
+        .map_or(0, |top| top + 1);
+
+    if m < n {
+        let mut limbs = [0; LIMBS];
+        limbs[..m].copy_from_slice(&numerator[..m]);
+        return BigUint { limbs };
+    }
+
+    if n == 1 {
+        let mut remainder = 0_u128;
+        for &limb in numerator[..m].iter().rev() {
+            remainder = ((remainder << 64) | limb as u128) % v[0] as u128;
+        }
+        return BigUint::from_u64(remainder as u64);
+    }
+
+    // Normalize so the top limb of the divisor has its high bit set
+    let shift = v[n - 1].leading_zeros();
+    let mut vn = vec![0_u64; n];
+    for i in (1..n).rev() {
+        vn[i] = (v[i] << shift)
+            | if shift > 0 {
+                v[i - 1] >> (64 - shift)
+            } else {
+                0
+            };
+    }
+    vn[0] = v[0] << shift;
+
+    let mut un = vec![0_u64; m + 1];
+    un[m] = if shift > 0 {
+        numerator[m - 1] >> (64 - shift)
+    } else {
+        0
+    };
+    for i in (1..m).rev() {
+        un[i] = (numerator[i] << shift)
+            | if shift > 0 {
+                numerator[i - 1] >> (64 - shift)
+            } else {
+                0
+            };
+    }
+    un[0] = numerator[0] << shift;
+
+    let base = 1_u128 << 64;
+    for j in (0..=m - n).rev() {
+        let top = ((un[j + n] as u128) << 64) | un[j + n - 1] as u128;
+        let mut qhat = top / vn[n - 1] as u128;
+        let mut rhat = top % vn[n - 1] as u128;
+        while qhat >= base || qhat * vn[n - 2] as u128 > ((rhat << 64) | un[j + n - 2] as u128) {
+            qhat -= 1;
+            rhat += vn[n - 1] as u128;
+            if rhat >= base {
+                break;
+            }
+        }
+
+        // un[j..j+n] -= qhat * vn
+        let mut borrow = 0_i128;
+        for i in 0..n {
+            let product = qhat * vn[i] as u128;
+            let t = un[i + j] as i128 - borrow - (product as u64) as i128;
+            un[i + j] = t as u64;
+            borrow = (product >> 64) as i128 - (t >> 64);
+        }
+        let t = un[j + n] as i128 - borrow;
+        un[j + n] = t as u64;
+
+        // qhat was one too large, add the divisor back
+        if t < 0 {
+            let mut carry = 0_u128;
+            for i in 0..n {
+                let sum = un[i + j] as u128 + vn[i] as u128 + carry;
+                un[i + j] = sum as u64;
+                carry = sum >> 64;
+            }
+            un[j + n] = un[j + n].wrapping_add(carry as u64);
+        }
+    }
+
+    // Undo the normalization on the remainder
+    let mut limbs = [0; LIMBS];
+    for i in 0..n {
+        limbs[i] = (un[i] >> shift)
+            | if shift > 0 {
+                un[i + 1] << (64 - shift)
+            } else {
+                0
+            };
+    }
+    BigUint { limbs }
+}
+
+impl Add for BigUint {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let (sum, carry) = self.overflowing_add(&other);
+        if carry {
+            panic!("BigUint addition overflow");
+        }
+        sum
+    }
+}
+
+impl Sub for BigUint {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let (diff, borrow) = self.overflowing_sub(&other);
+        if borrow {
+            panic!("BigUint subtraction underflow");
+        }
+        diff
+    }
+}
+
+impl Mul for BigUint {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let mut wide = [0_u64; 2 * LIMBS];
+        for i in 0..LIMBS {
+            let mut carry = 0_u128;
+            for j in 0..LIMBS {
+                let acc =
+                    wide[i + j] as u128 + self.limbs[i] as u128 * other.limbs[j] as u128 + carry;
+                wide[i + j] = acc as u64;
+                carry = acc >> 64;
+            }
+            wide[i + LIMBS] = carry as u64;
+        }
+        if wide[LIMBS..].iter().any(|&limb| limb != 0) {
+            panic!("BigUint multiplication overflow");
+        }
+        let mut limbs = [0; LIMBS];
+        limbs.copy_from_slice(&wide[..LIMBS]);
+        BigUint { limbs }
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        BigUint::from_u64(value)
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0x")?;
+        let top = self.limbs.iter().rposition(|&limb| limb != 0).unwrap_or(0);
+        write!(f, "{:x}", self.limbs[top])?;
+        for limb in self.limbs[..top].iter().rev() {
+            write!(f, "{:016x}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn big(hex: &str) -> BigUint {
+        BigUint::from_hex(hex).unwrap()
+    }
+
+    #[test]
+    fn test_from_hex() {
+        assert_eq!(big("0xff"), BigUint::from_u64(255));
+        assert_eq!(big("1_0000_0000_0000_0000").limbs()[1], 1);
+        assert!(BigUint::from_hex("0x").is_err());
+        assert!(BigUint::from_hex("0xfg").is_err());
+    }
+
+    #[test]
+    fn test_from_hex_too_large() {
+        let hex = format!("1{}", "0".repeat(96));
+        assert!(BigUint::from_hex(&hex).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", BigUint::zero()), "0x0");
+        assert_eq!(
+            format!("{}", big("0x1000000000000000f")),
+            "0x1000000000000000f"
+        );
+    }
+
+    #[test]
+    fn test_bits() {
+        assert_eq!(BigUint::zero().bits(), 0);
+        assert_eq!(BigUint::one().bits(), 1);
+        assert_eq!(big("0x10000000000000000").bits(), 65);
+    }
+
+    #[test]
+    fn test_shifts() {
+        let x = big("0x123456789abcdef0123456789");
+        assert_eq!(x.shl(68).shr(68), x);
+        assert_eq!(x.shr(4), big("0x123456789abcdef012345678"));
+        assert_eq!(BigUint::one().shl(383).bits(), 384);
+    }
+
+    #[test]
+    fn test_add_and_sub() {
+        let x = big("0xffffffffffffffffffffffffffffffff");
+        let sum = x + BigUint::one();
+        assert_eq!(sum, big("0x100000000000000000000000000000000"));
+        assert_eq!(sum - BigUint::one(), x);
+    }
+
+    #[test]
+    #[should_panic(expected = "BigUint subtraction underflow")]
+    fn test_sub_underflow_should_panic() {
+        let _ = BigUint::zero() - BigUint::one();
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(big("0x10000000000000000") > big("0xffffffffffffffff"));
+        assert!(BigUint::one() < BigUint::from_u64(2));
+    }
+
+    // Expected values computed with Python's arbitrary precision integers
+    #[test]
+    fn test_mul_mod() {
+        let p = big("0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let x = big("0x79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+        let y = big("0x483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8");
+        assert_eq!(
+            x.mul_mod(&y, &p),
+            big("0xfd3dc529c6eb60fb9d166034cf3c1a5a72324aa9dfd3428a56d7e1ce0179fd9b")
+        );
+        assert_eq!(
+            x.mul_mod(&x, &p),
+            big("0x8550e7d238fcf3086ba9adcf0fb52a9de3652194d06cb5bb38d50229b854fc49")
+        );
+    }
+
+    #[test]
+    fn test_mul_mod_small_modulus() {
+        let x = big("0x123456789abcdef0123456789abcdef");
+        assert_eq!(
+            x.mul_mod(&x, &BigUint::from_u64(1000003)),
+            BigUint::from_u64(885140)
+        );
+    }
+
+    #[test]
+    fn test_div_rem_u64() {
+        let p = big("0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab");
+        let (quotient, remainder) = p.div_rem_u64(6);
+        assert_eq!(remainder, 1);
+        assert_eq!(quotient * BigUint::from_u64(6) + BigUint::one(), p);
+        assert_eq!(BigUint::from_u64(7).div_rem_u64(8), (BigUint::zero(), 7));
+    }
+
+    #[test]
+    fn test_rem() {
+        let x = big("0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab");
+        let r = big("0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+        assert_eq!(
+            x.rem(&r),
+            big("0x73eda753299d7d483339d80809a1d80553bda402fffe5bfe2dfefffeffff0001")
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+// p = 2^255 - 19, little-endian 64-bit limbs
+const P: [u64; 4] = [
+    0xffff_ffff_ffff_ffed,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0x7fff_ffff_ffff_ffff,
+];
+
+// Field element modulo 2^255 - 19. Felt only holds 64-bit moduli, so
+// Curve25519 and Ed25519 arithmetic uses this fixed-modulus type instead.
+//
+// Limbs are kept below 2^256 but not necessarily below p, values are only
+// fully reduced when encoded or compared.
+#[derive(Debug, Clone, Copy)]
+pub struct Fe25519 {
+    limbs: [u64; 4],
+}
+
+impl Fe25519 {
+    pub fn zero() -> Self {
+        Fe25519 { limbs: [0; 4] }
+    }
+
+    pub fn one() -> Self {
+        Fe25519::from_u64(1)
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        Fe25519 {
+            limbs: [value, 0, 0, 0],
+        }
+    }
+
+    // Little-endian decoding, the most significant bit is ignored (RFC 7748)
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        let mut limbs = [0_u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let mut chunk = [0_u8; 8];
+            chunk.copy_from_slice(&bytes[8 * i..8 * i + 8]);
+            *limb = u64::from_le_bytes(chunk);
+        }
+        limbs[3] &= 0x7fff_ffff_ffff_ffff;
+        Fe25519 { limbs }
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let limbs = self.reduced();
+        let mut bytes = [0_u8; 32];
+        for (i, limb) in limbs.iter().enumerate() {
+            bytes[8 * i..8 * i + 8].copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.reduced() == [0; 4]
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    // exponent is a little-endian 256-bit integer
+    pub fn pow(&self, exponent: &[u8; 32]) -> Self {
+        let mut result = Fe25519::one();
+        for i in (0..256).rev() {
+            result = result.square();
+            if (exponent[i / 8] >> (i % 8)) & 1 == 1 {
+                result = result * *self;
+            }
+        }
+        result
+    }
+
+    // Fermat's little theorem: x^(p-2) = x^-1, and 0 maps to 0
+    pub fn invert(&self) -> Self {
+        let mut exponent = P;
+        exponent[0] -= 2;
+        let mut bytes = [0_u8; 32];
+        for (i, limb) in exponent.iter().enumerate() {
+            bytes[8 * i..8 * i + 8].copy_from_slice(&limb.to_le_bytes());
+        }
+        self.pow(&bytes)
+    }
+
+    // Swaps a and b when swap is 1 and leaves them when it is 0, without
+    // branching on swap
+    pub fn cswap(a: &mut Self, b: &mut Self, swap: u64) {
+        let mask = 0_u64.wrapping_sub(swap);
+        for i in 0..4 {
+            let t = mask & (a.limbs[i] ^ b.limbs[i]);
+            a.limbs[i] ^= t;
+            b.limbs[i] ^= t;
+        }
+    }
+
+    // Canonical representative in [0, p)
+    fn reduced(&self) -> [u64; 4] {
+        let mut limbs = self.limbs;
+        // limbs < 2^256 < 3p, so at most two subtractions are needed
+        for _ in 0..2 {
+            let (diff, borrow) = sub_limbs(&limbs, &P);
+            if !borrow {
+                limbs = diff;
+            }
+        }
+        limbs
+    }
+
+    // Adds carry * 2^256 = carry * 38 (mod p) until the value fits in 4 limbs
+    fn fold(mut limbs: [u64; 4], mut carry: u64) -> Self {
+        while carry > 0 {
+            let mut acc = carry as u128 * 38;
+            for limb in limbs.iter_mut() {
+                acc += *limb as u128;
+                *limb = acc as u64;
+                acc >>= 64;
+            }
+            carry = acc as u64;
+        }
+        Fe25519 { limbs }
+    }
+}
+
+fn sub_limbs(lhs: &[u64; 4], rhs: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut result = [0_u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (diff, b1) = lhs[i].overflowing_sub(rhs[i]);
+        let (diff, b2) = diff.overflowing_sub(borrow as u64);
+        result[i] = diff;
+        borrow = b1 || b2;
+    }
+    (result, borrow)
+}
+
+impl Add for Fe25519 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut limbs = [0_u64; 4];
+        let mut acc = 0_u128;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            acc += self.limbs[i] as u128 + other.limbs[i] as u128;
+            *limb = acc as u64;
+            acc >>= 64;
+        }
+        Fe25519::fold(limbs, acc as u64)
+    }
+}
+
+impl Sub for Fe25519 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let (mut limbs, mut borrow) = sub_limbs(&self.limbs, &other.limbs);
+        // A borrow added 2^256 = 38 (mod p), take it back out
+        while borrow {
+            (limbs, borrow) = sub_limbs(&limbs, &[38, 0, 0, 0]);
+        }
+        Fe25519 { limbs }
+    }
+}
+
+impl Mul for Fe25519 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let mut wide = [0_u64; 8];
+        for i in 0..4 {
+            let mut carry = 0_u128;
+            for j in 0..4 {
+                let acc =
+                    wide[i + j] as u128 + self.limbs[i] as u128 * other.limbs[j] as u128 + carry;
+                wide[i + j] = acc as u64;
+                carry = acc >> 64;
+            }
+            wide[i + 4] = carry as u64;
+        }
+
+        // lo + hi * 2^256 = lo + hi * 38 (mod p)
+        let mut limbs = [0_u64; 4];
+        let mut acc = 0_u128;
+        for i in 0..4 {
+            acc += wide[i] as u128 + wide[i + 4] as u128 * 38;
+            limbs[i] = acc as u64;
+            acc >>= 64;
+        }
+        Fe25519::fold(limbs, acc as u64)
+    }
+}
+
+impl Neg for Fe25519 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fe25519::zero() - self
+    }
+}
+
+impl PartialEq for Fe25519 {
+    fn eq(&self, other: &Self) -> bool {
+        self.reduced() == other.reduced()
+    }
+}
+
+impl Eq for Fe25519 {}
+
+impl std::fmt::Display for Fe25519 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "0x")?;
+        for limb in self.reduced().iter().rev() {
+            write!(f, "{:016x}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_p_is_zero() {
+        let p = Fe25519 { limbs: P };
+        assert!(p.is_zero());
+        assert_eq!(p, Fe25519::zero());
+    }
+
+    #[test]
+    fn test_add_wraps_around_modulus() {
+        let minus_one = -Fe25519::one();
+        assert_eq!(minus_one + Fe25519::from_u64(5), Fe25519::from_u64(4));
+    }
+
+    #[test]
+    fn test_subtract_with_borrow() {
+        let f = Fe25519::from_u64(3) - Fe25519::from_u64(5);
+        assert_eq!(f + Fe25519::from_u64(2), Fe25519::zero());
+    }
+
+    #[test]
+    fn test_multiply() {
+        // 2^128 * 2^128 = 2^256 = 38 (mod p)
+        let f = Fe25519 {
+            limbs: [0, 0, 1, 0],
+        };
+        assert_eq!(f * f, Fe25519::from_u64(38));
+    }
+
+    #[test]
+    fn test_multiply_with_inverse_should_equal_one() {
+        let f = Fe25519::from_u64(121665);
+        assert_eq!(f * f.invert(), Fe25519::one());
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let mut bytes = [0_u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i * 7) as u8;
+        }
+        bytes[31] &= 0x7f;
+        assert_eq!(Fe25519::from_bytes(&bytes).to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_cswap() {
+        let (mut a, mut b) = (Fe25519::from_u64(3), Fe25519::from_u64(5));
+        Fe25519::cswap(&mut a, &mut b, 0);
+        assert_eq!((a, b), (Fe25519::from_u64(3), Fe25519::from_u64(5)));
+        Fe25519::cswap(&mut a, &mut b, 1);
+        assert_eq!((a, b), (Fe25519::from_u64(5), Fe25519::from_u64(3)));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!("{}", Fe25519::from_u64(255)),
+            "0x00000000000000000000000000000000000000000000000000000000000000ff"
+        );
+    }
+}
@@ -1,6 +1,12 @@
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::{
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
 
-use super::felt_errors::FeltError;
+use super::{
+    felt_errors::FeltError,
+    parser::{signed_felt, Parser},
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct Felt {
@@ -62,6 +68,126 @@ impl Felt {
         result
     }
 
+    // Tonelli-Shanks, the modulus must be a prime. Over F_2 every element is
+    // its own square root.
+    pub fn sqrt(&self) -> Option<Self> {
+        let modulus = self.modulus;
+        let zero = Felt::new(0, modulus);
+        let one = Felt::new(1, modulus);
+
+        if *self == zero || modulus == 2 {
+            return Some(*self);
+        }
+        if self.pow((modulus - 1) / 2) != one {
+            return None;
+        }
+
+        // modulus - 1 = q * 2^s with q odd
+        let mut q = modulus - 1;
+        let mut s = 0;
+        while q.is_multiple_of(2) {
+            q /= 2;
+            s += 1;
+        }
+
+        let z = Felt::smallest_nonresidue(modulus)?;
+
+        let mut m = s;
+        let mut c = z.pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow(q.div_ceil(2));
+
+        while t != one {
+            let mut i = 0;
+            let mut t_pow = t;
+            while t_pow != one {
+                t_pow = t_pow * t_pow;
+                i += 1;
+            }
+
+            let b = c.pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t = t * c;
+            r = r * b;
+        }
+
+        Some(r)
+    }
+
+    // The smallest quadratic non-residue by Euler's criterion, the modulus
+    // must be a prime. None over F_2, where every element is a square.
+    pub fn smallest_nonresidue(modulus: u64) -> Option<Self> {
+        let one = Felt::new(1, modulus);
+        (2..modulus)
+            .map(|z| Felt::new(z, modulus))
+            .find(|z| z.pow((modulus - 1) / 2) != one)
+    }
+
+    // One cube root, the modulus must be a prime. When modulus = 1 (mod 3)
+    // the other roots are this one times the primitive cube roots of unity.
+    pub fn cbrt(&self) -> Option<Self> {
+        let modulus = self.modulus;
+        let one = Felt::new(1, modulus);
+
+        if self.value == 0 || modulus <= 3 {
+            return Some(*self);
+        }
+        // Cubing is a bijection, its inverse is x^((2p - 1) / 3)
+        if modulus % 3 == 2 {
+            let exponent = ((2 * modulus as u128 - 1) / 3) as u64;
+            return Some(self.pow(exponent));
+        }
+        if self.pow((modulus - 1) / 3) != one {
+            return None;
+        }
+
+        // modulus - 1 = t * 3^s with t not divisible by 3
+        let mut t = modulus - 1;
+        let mut s = 0;
+        while t.is_multiple_of(3) {
+            t /= 3;
+            s += 1;
+        }
+
+        // r^3 = self * b with b in the 3-Sylow subgroup
+        let e = if t % 3 == 2 {
+            (t + 1) / 3
+        } else {
+            (2 * t + 1) / 3
+        };
+        let r = self.pow(e);
+        let b = r.pow(3) / *self;
+
+        // g generates the 3-Sylow subgroup, find x with g^x = b. The search
+        // for a non-cube z only ends because of the modulus <= 3 return
+        // above: a prime p = 1 (mod 3) always has non-cubes.
+        let mut z = Felt::new(2, modulus);
+        while z.pow((modulus - 1) / 3) == one {
+            z = z + one;
+        }
+        let g = z.pow(t);
+        let gamma = g.pow(3_u64.pow(s - 1));
+
+        let mut x = 0;
+        let mut power_of_three = 1;
+        for i in 0..s {
+            let h = (b / g.pow(x)).pow(3_u64.pow(s - 1 - i));
+            let digit = if h == one {
+                0
+            } else if h == gamma {
+                1
+            } else {
+                2
+            };
+            x += digit * power_of_three;
+            power_of_three *= 3;
+        }
+
+        // b = g^x with 3 | x, so (r / g^(x/3))^3 = self
+        Some(r / g.pow(x / 3))
+    }
+
     pub fn value(&self) -> u64 {
         self.value
     }
@@ -141,6 +267,23 @@ impl std::fmt::Display for Felt {
     }
 }
 
+// Parses the Display format "5 (mod 7)". Both numbers may be decimal or
+// 0x-prefixed hex and the value may be negative, e.g. "-0x3 (mod 1021)".
+impl FromStr for Felt {
+    type Err = FeltError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let parse = |parser: &mut Parser| {
+            let (_, negative, value) = parser.integer()?;
+            let modulus = parser.modulus()?;
+            parser.expect_end()?;
+            Ok(signed_felt(negative, value, modulus))
+        };
+        parse(&mut parser).map_err(|(position, message)| FeltError::Parse(position, message))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -371,6 +514,74 @@ mod test {
         assert_eq!(f_pow.modulus, 7);
     }
 
+    #[test]
+    fn test_sqrt() {
+        let f = Felt::new(2, 7);
+        let root = f.sqrt().unwrap();
+        assert_eq!(root * root, f);
+    }
+
+    #[test]
+    fn test_sqrt_of_zero() {
+        let f = Felt::new(0, 7);
+        assert_eq!(f.sqrt(), Some(f));
+    }
+
+    #[test]
+    fn test_sqrt_of_non_residue_should_be_none() {
+        let f = Felt::new(3, 7);
+        assert_eq!(f.sqrt(), None);
+    }
+
+    #[test]
+    fn test_sqrt_over_f2() {
+        assert_eq!(Felt::new(1, 2).sqrt(), Some(Felt::new(1, 2)));
+        assert_eq!(Felt::new(0, 2).sqrt(), Some(Felt::new(0, 2)));
+    }
+
+    #[test]
+    fn test_smallest_nonresidue() {
+        assert_eq!(Felt::smallest_nonresidue(2), None);
+        assert_eq!(Felt::smallest_nonresidue(7), Some(Felt::new(3, 7)));
+        assert_eq!(Felt::smallest_nonresidue(41), Some(Felt::new(3, 41)));
+        assert_eq!(Felt::smallest_nonresidue(71), Some(Felt::new(7, 71)));
+    }
+
+    #[test]
+    fn test_sqrt_modulus_one_mod_eight() {
+        let modulus = 1201;
+        for value in 0..modulus {
+            let f = Felt::new(value, modulus);
+            if let Some(root) = f.sqrt() {
+                assert_eq!(root * root, f);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cbrt_modulus_two_mod_three() {
+        let modulus = 11;
+        for value in 0..modulus {
+            let f = Felt::new(value, modulus);
+            assert_eq!(f.cbrt().unwrap().pow(3), f);
+        }
+    }
+
+    #[test]
+    fn test_cbrt_modulus_one_mod_three() {
+        // 108 = 4 * 27, so the 3-Sylow subgroup has order 27
+        let modulus = 109;
+        let mut cubes = 0;
+        for value in 0..modulus {
+            let f = Felt::new(value, modulus);
+            if let Some(root) = f.cbrt() {
+                assert_eq!(root.pow(3), f);
+                cubes += 1;
+            }
+        }
+        assert_eq!(cubes, 37);
+    }
+
     #[test]
     fn test_negative_felt() {
         let f = Felt::new(5, 7);
@@ -405,4 +616,43 @@ mod test {
         let f = Felt::new(5, 7);
         assert_eq!(format!("{}", f), "5 (mod 7)");
     }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for f in [
+            Felt::new(5, 7),
+            Felt::new(0, 2),
+            Felt::new(u64::MAX - 1, u64::MAX),
+        ] {
+            assert_eq!(f.to_string().parse::<Felt>().unwrap(), f);
+        }
+    }
+
+    #[test]
+    fn test_parse_hex_and_negative() {
+        assert_eq!(
+            "0xff (mod 0x101)".parse::<Felt>().unwrap(),
+            Felt::new(255, 257)
+        );
+        assert_eq!(
+            "-3 (mod 1021)".parse::<Felt>().unwrap(),
+            -Felt::new(3, 1021)
+        );
+        assert_eq!("  12(mod 7)  ".parse::<Felt>().unwrap(), Felt::new(5, 7));
+    }
+
+    #[test]
+    fn test_parse_error_positions() {
+        let position = |s: &str| match s.parse::<Felt>() {
+            Err(FeltError::Parse(position, _)) => position,
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(position(""), 0);
+        assert_eq!(position("5 mod 7)"), 2);
+        assert_eq!(position("5 (mod 0)"), 7);
+        assert_eq!(position("5 (mod -7)"), 7);
+        assert_eq!(position("5 (mod 7"), 8);
+        assert_eq!(position("5 (mod 7) x"), 10);
+        assert_eq!(position("18446744073709551616 (mod 7)"), 0);
+    }
 }
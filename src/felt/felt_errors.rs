@@ -1,7 +1,15 @@
 use thiserror::Error;
 
+use super::big_uint::BigUint;
+
 #[derive(Debug, Error)]
 pub enum FeltError {
     #[error("{0} is not invertible (mod {1})")]
     NotInvertible(u64, u64),
+    #[error("{0} is not invertible (mod {1})")]
+    BigNotInvertible(BigUint, BigUint),
+    #[error("Invalid hexadecimal integer: {0:?}")]
+    InvalidHex(String),
+    #[error("Invalid field element at position {0}: {1}")]
+    Parse(usize, String),
 }
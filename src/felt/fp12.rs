@@ -0,0 +1,195 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use super::{big_fp2::BigFp2, big_uint::BigUint, felt_errors::FeltError, fp6::Fp6};
+
+// Element c0 + c1 w of F_p^12 = F_p^6[w] / (w^2 - v), so w^6 = xi. Pairings
+// on BN and BLS12 curves take their values here.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Fp12 {
+    c0: Fp6,
+    c1: Fp6,
+}
+
+impl Fp12 {
+    pub fn new(c0: Fp6, c1: Fp6) -> Self {
+        if c0.xi() != c1.xi() {
+            panic!("Cannot build an Fp12 value from different fields");
+        }
+        Fp12 { c0, c1 }
+    }
+
+    // From the coefficients of 1, w, ..., w^5
+    pub fn from_coefficients(coefficients: [BigFp2; 6], xi: BigFp2) -> Self {
+        let [a0, b0, a1, b1, a2, b2] = coefficients;
+        Fp12::new(Fp6::new(a0, a1, a2, xi), Fp6::new(b0, b1, b2, xi))
+    }
+
+    pub fn one(xi: BigFp2) -> Self {
+        Fp12::new(Fp6::one(xi), Fp6::zero(xi))
+    }
+
+    pub fn c0(&self) -> Fp6 {
+        self.c0
+    }
+
+    pub fn c1(&self) -> Fp6 {
+        self.c1
+    }
+
+    pub fn xi(&self) -> BigFp2 {
+        self.c0.xi()
+    }
+
+    pub fn is_one(&self) -> bool {
+        *self == Fp12::one(self.xi())
+    }
+
+    // Coefficients of 1, w, ..., w^5
+    pub fn coefficients(&self) -> [BigFp2; 6] {
+        let (a, b) = (self.c0, self.c1);
+        [a.c0(), b.c0(), a.c1(), b.c1(), a.c2(), b.c2()]
+    }
+
+    // x^(p^6) = c0 - c1 w, the inverse on the cyclotomic subgroup where
+    // pairing values live
+    pub fn conjugate(&self) -> Self {
+        Fp12::new(self.c0, -self.c1)
+    }
+
+    // x^p, (a w^k)^p = conj(a) w^k xi^(k (p - 1) / 6)
+    pub fn frobenius(&self) -> Self {
+        let xi = self.xi();
+        let (exponent, _) = (xi.modulus() - BigUint::one()).div_rem_u64(6);
+        self.frobenius_with(xi.pow(&exponent))
+    }
+
+    // frobenius given gamma = xi^((p - 1) / 6), for callers that precompute it
+    pub fn frobenius_with(&self, gamma: BigFp2) -> Self {
+        let xi = self.xi();
+        let mut power = BigFp2::one(xi.modulus());
+        let mut coefficients = self.coefficients();
+        for coefficient in coefficients.iter_mut() {
+            *coefficient = coefficient.conjugate() * power;
+            power = power * gamma;
+        }
+        Fp12::from_coefficients(coefficients, xi)
+    }
+
+    // (c0 - c1 w) / (c0^2 - v c1^2)
+    pub fn inverse(&self) -> Result<Self, FeltError> {
+        let norm = self.c0 * self.c0 - (self.c1 * self.c1).mul_by_v();
+        let norm_inverse = norm.inverse()?;
+        Ok(Fp12::new(self.c0 * norm_inverse, -self.c1 * norm_inverse))
+    }
+
+    pub fn pow(&self, exponent: &BigUint) -> Self {
+        let mut result = Fp12::one(self.xi());
+        for i in (0..exponent.bits()).rev() {
+            result = result * result;
+            if exponent.bit(i) {
+                result = result * *self;
+            }
+        }
+        result
+    }
+}
+
+impl Add for Fp12 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Fp12::new(self.c0 + other.c0, self.c1 + other.c1)
+    }
+}
+
+impl Sub for Fp12 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Fp12::new(self.c0 - other.c0, self.c1 - other.c1)
+    }
+}
+
+impl Mul for Fp12 {
+    type Output = Self;
+
+    // Karatsuba with w^2 = v, 3 multiplications in F_p^6
+    fn mul(self, other: Self) -> Self {
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        Fp12::new(
+            v0 + v1.mul_by_v(),
+            (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1,
+        )
+    }
+}
+
+impl Neg for Fp12 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fp12::new(-self.c0, -self.c1)
+    }
+}
+
+impl Display for Fp12 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}) + ({})w", self.c0, self.c1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn p() -> BigUint {
+        BigUint::from_hex("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47")
+            .unwrap()
+    }
+
+    fn element(seed: u64) -> Fp12 {
+        let fp2 = |i: u64| BigFp2::from_u64(seed * i + 1, seed + i * i, p());
+        Fp12::from_coefficients(
+            [fp2(0), fp2(1), fp2(2), fp2(3), fp2(4), fp2(5)],
+            BigFp2::from_u64(9, 1, p()),
+        )
+    }
+
+    #[test]
+    fn test_w_to_the_sixth_is_xi() {
+        let xi = BigFp2::from_u64(9, 1, p());
+        let zero = BigFp2::zero(p());
+        let w = Fp12::from_coefficients([zero, BigFp2::one(p()), zero, zero, zero, zero], xi);
+        let w6 = Fp12::from_coefficients([xi, zero, zero, zero, zero, zero], xi);
+        assert_eq!(w.pow(&BigUint::from_u64(6)), w6);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (x, y, z) = (element(3), element(5), element(7));
+        assert_eq!(x * y, y * x);
+        assert_eq!(x * (y + z), x * y + x * z);
+        assert_eq!((x * y) * z, x * (y * z));
+        assert_eq!(x - y + y, x);
+        assert_eq!(x * x.inverse().unwrap(), Fp12::one(x.xi()));
+        assert_eq!(-x + x, x - x);
+    }
+
+    #[test]
+    fn test_frobenius_is_pth_power() {
+        let x = element(3);
+        assert_eq!(x.frobenius(), x.pow(&p()));
+        let mut power = x;
+        for _ in 0..6 {
+            power = power.frobenius();
+        }
+        assert_eq!(power, x.conjugate());
+        for _ in 0..6 {
+            power = power.frobenius();
+        }
+        assert_eq!(power, x);
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::{felt::Felt, felt_errors::FeltError};
+
+// Element c0 + c1 u of F_p^2 = F_p[u] / (u^2 - beta) for a non-residue beta
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Fp2 {
+    c0: Felt,
+    c1: Felt,
+    nonresidue: Felt,
+}
+
+impl Fp2 {
+    pub fn new(c0: Felt, c1: Felt, nonresidue: Felt) -> Self {
+        Fp2 { c0, c1, nonresidue }
+    }
+
+    // -1 when p = 3 (mod 4), so u = sqrt(-1), else the smallest non-residue
+    pub fn default_nonresidue(modulus: u64) -> Felt {
+        let minus_one = -Felt::new(1, modulus);
+        if modulus % 4 == 3 {
+            return minus_one;
+        }
+        Felt::smallest_nonresidue(modulus)
+            .unwrap_or_else(|| panic!("Every element of F_{} is a square", modulus))
+    }
+
+    pub fn from_felt(value: Felt, nonresidue: Felt) -> Self {
+        Fp2::new(value, Felt::new(0, value.modulus()), nonresidue)
+    }
+
+    pub fn zero(nonresidue: Felt) -> Self {
+        Fp2::from_felt(Felt::new(0, nonresidue.modulus()), nonresidue)
+    }
+
+    pub fn one(nonresidue: Felt) -> Self {
+        Fp2::from_felt(Felt::new(1, nonresidue.modulus()), nonresidue)
+    }
+
+    pub fn c0(&self) -> Felt {
+        self.c0
+    }
+
+    pub fn c1(&self) -> Felt {
+        self.c1
+    }
+
+    pub fn nonresidue(&self) -> Felt {
+        self.nonresidue
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.c0.modulus()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.c0.value() == 0 && self.c1.value() == 0
+    }
+
+    // Whether the element is in the subfield F_p
+    pub fn is_felt(&self) -> bool {
+        self.c1.value() == 0
+    }
+
+    // N(x) = x * conj(x) = c0^2 - beta c1^2, in F_p
+    pub fn norm(&self) -> Felt {
+        self.c0.pow(2) - self.nonresidue * self.c1.pow(2)
+    }
+
+    // x^p = c0 - c1 u since u^p = beta^((p - 1) / 2) u = -u
+    pub fn frobenius(&self) -> Self {
+        Fp2::new(self.c0, -self.c1, self.nonresidue)
+    }
+
+    // conj(x) / N(x)
+    pub fn inverse(&self) -> Result<Self, FeltError> {
+        let norm_inverse = self.norm().inverse()?;
+        Ok(Fp2::new(
+            self.c0 * norm_inverse,
+            -self.c1 * norm_inverse,
+            self.nonresidue,
+        ))
+    }
+
+    pub fn pow(&self, exponent: u64) -> Self {
+        let mut result = Fp2::one(self.nonresidue);
+        for i in (0..u64::BITS - exponent.leading_zeros()).rev() {
+            result = result * result;
+            if (exponent >> i) & 1 == 1 {
+                result = result * *self;
+            }
+        }
+        result
+    }
+
+    fn check_field(&self, other: &Fp2, operation: &str) {
+        if self.nonresidue != other.nonresidue {
+            panic!("Cannot {} two Fp2 values from different fields", operation);
+        }
+    }
+}
+
+impl Add for Fp2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.check_field(&other, "add");
+        Fp2::new(self.c0 + other.c0, self.c1 + other.c1, self.nonresidue)
+    }
+}
+
+impl Sub for Fp2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.check_field(&other, "subtract");
+        Fp2::new(self.c0 - other.c0, self.c1 - other.c1, self.nonresidue)
+    }
+}
+
+impl Mul for Fp2 {
+    type Output = Self;
+
+    // (a0 + a1 u)(b0 + b1 u) = a0 b0 + beta a1 b1 + (a0 b1 + a1 b0) u
+    fn mul(self, other: Self) -> Self {
+        self.check_field(&other, "multiply");
+        Fp2::new(
+            self.c0 * other.c0 + self.nonresidue * self.c1 * other.c1,
+            self.c0 * other.c1 + self.c1 * other.c0,
+            self.nonresidue,
+        )
+    }
+}
+
+impl Mul<Felt> for Fp2 {
+    type Output = Self;
+
+    fn mul(self, other: Felt) -> Self {
+        Fp2::new(self.c0 * other, self.c1 * other, self.nonresidue)
+    }
+}
+
+impl Div for Fp2 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.check_field(&other, "divide");
+        match other.inverse() {
+            Ok(inverse) => self.mul(inverse),
+            Err(_) => panic!("Cannot divide by zero"),
+        }
+    }
+}
+
+impl Neg for Fp2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fp2::new(-self.c0, -self.c1, self.nonresidue)
+    }
+}
+
+impl Display for Fp2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} + {}u (mod {})",
+            self.c0.value(),
+            self.c1.value(),
+            self.modulus()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn element(c0: u64, c1: u64) -> Fp2 {
+        Fp2::new(
+            Felt::new(c0, 43),
+            Felt::new(c1, 43),
+            Fp2::default_nonresidue(43),
+        )
+    }
+
+    #[test]
+    fn test_default_nonresidue() {
+        assert_eq!(Fp2::default_nonresidue(43), -Felt::new(1, 43));
+        // 2 is a square mod 41 but 3 is not
+        assert_eq!(Fp2::default_nonresidue(41), Felt::new(3, 41));
+    }
+
+    #[test]
+    #[should_panic(expected = "Every element of F_2 is a square")]
+    fn test_default_nonresidue_over_f2_should_panic() {
+        Fp2::default_nonresidue(2);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let x = element(3, 5);
+        let y = element(7, 2);
+        // (3 + 5u)(7 + 2u) = 21 - 10 + (6 + 35)u with u^2 = -1
+        assert_eq!(x * y, element(11, 41));
+        assert_eq!(x + y, element(10, 7));
+        assert_eq!(x - y, element(39, 3));
+        assert_eq!(x / y * y, x);
+        assert_eq!(x * x.inverse().unwrap(), Fp2::one(x.nonresidue()));
+        assert_eq!(x + -x, Fp2::zero(x.nonresidue()));
+    }
+
+    #[test]
+    fn test_frobenius_is_pth_power() {
+        for x in [element(3, 5), element(0, 1), element(42, 17)] {
+            assert_eq!(x.pow(43), x.frobenius());
+            // The multiplicative group has order p^2 - 1
+            assert_eq!(x.pow(43 * 43 - 1), Fp2::one(x.nonresidue()));
+        }
+
+        let beta = Fp2::default_nonresidue(41);
+        let x = Fp2::new(Felt::new(4, 41), Felt::new(9, 41), beta);
+        assert_eq!(x.pow(41), x.frobenius());
+        assert_eq!(x.norm(), (x * x.frobenius()).c0());
+    }
+
+    #[test]
+    fn test_inverse_of_zero() {
+        assert!(element(0, 0).inverse().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot add two Fp2 values from different fields")]
+    fn test_add_from_different_fields_should_panic() {
+        let beta = Felt::new(3, 43);
+        let _ = element(1, 1) + Fp2::one(-beta);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(element(3, 5).to_string(), "3 + 5u (mod 43)");
+    }
+}
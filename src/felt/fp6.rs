@@ -0,0 +1,186 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use super::{big_fp2::BigFp2, felt_errors::FeltError};
+
+// Element c0 + c1 v + c2 v^2 of F_p^6 = F_p^2[v] / (v^3 - xi) for a
+// non-cube xi in F_p^2, the middle of the F_p^12 tower
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Fp6 {
+    c0: BigFp2,
+    c1: BigFp2,
+    c2: BigFp2,
+    xi: BigFp2,
+}
+
+impl Fp6 {
+    pub fn new(c0: BigFp2, c1: BigFp2, c2: BigFp2, xi: BigFp2) -> Self {
+        Fp6 { c0, c1, c2, xi }
+    }
+
+    pub fn from_fp2(value: BigFp2, xi: BigFp2) -> Self {
+        let zero = BigFp2::zero(xi.modulus());
+        Fp6::new(value, zero, zero, xi)
+    }
+
+    pub fn zero(xi: BigFp2) -> Self {
+        Fp6::from_fp2(BigFp2::zero(xi.modulus()), xi)
+    }
+
+    pub fn one(xi: BigFp2) -> Self {
+        Fp6::from_fp2(BigFp2::one(xi.modulus()), xi)
+    }
+
+    pub fn c0(&self) -> BigFp2 {
+        self.c0
+    }
+
+    pub fn c1(&self) -> BigFp2 {
+        self.c1
+    }
+
+    pub fn c2(&self) -> BigFp2 {
+        self.c2
+    }
+
+    pub fn xi(&self) -> BigFp2 {
+        self.xi
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+
+    // v (c0 + c1 v + c2 v^2) = xi c2 + c0 v + c1 v^2
+    pub fn mul_by_v(&self) -> Self {
+        Fp6::new(self.xi * self.c2, self.c0, self.c1, self.xi)
+    }
+
+    // The adjugate over the norm to F_p^2
+    pub fn inverse(&self) -> Result<Self, FeltError> {
+        let (a0, a1, a2, xi) = (self.c0, self.c1, self.c2, self.xi);
+        let t0 = a0 * a0 - xi * a1 * a2;
+        let t1 = xi * a2 * a2 - a0 * a1;
+        let t2 = a1 * a1 - a0 * a2;
+        let norm_inverse = (a0 * t0 + xi * (a2 * t1 + a1 * t2)).inverse()?;
+        Ok(Fp6::new(
+            t0 * norm_inverse,
+            t1 * norm_inverse,
+            t2 * norm_inverse,
+            xi,
+        ))
+    }
+
+    fn check_field(&self, other: &Fp6, operation: &str) {
+        if self.xi != other.xi {
+            panic!("Cannot {} two Fp6 values from different fields", operation);
+        }
+    }
+}
+
+impl Add for Fp6 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.check_field(&other, "add");
+        Fp6::new(
+            self.c0 + other.c0,
+            self.c1 + other.c1,
+            self.c2 + other.c2,
+            self.xi,
+        )
+    }
+}
+
+impl Sub for Fp6 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.check_field(&other, "subtract");
+        Fp6::new(
+            self.c0 - other.c0,
+            self.c1 - other.c1,
+            self.c2 - other.c2,
+            self.xi,
+        )
+    }
+}
+
+impl Mul for Fp6 {
+    type Output = Self;
+
+    // Karatsuba with v^3 = xi, 6 multiplications in F_p^2
+    fn mul(self, other: Self) -> Self {
+        self.check_field(&other, "multiply");
+        let (a0, a1, a2) = (self.c0, self.c1, self.c2);
+        let (b0, b1, b2) = (other.c0, other.c1, other.c2);
+        let v0 = a0 * b0;
+        let v1 = a1 * b1;
+        let v2 = a2 * b2;
+        Fp6::new(
+            v0 + self.xi * ((a1 + a2) * (b1 + b2) - v1 - v2),
+            (a0 + a1) * (b0 + b1) - v0 - v1 + self.xi * v2,
+            (a0 + a2) * (b0 + b2) - v0 - v2 + v1,
+            self.xi,
+        )
+    }
+}
+
+impl Neg for Fp6 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fp6::new(-self.c0, -self.c1, -self.c2, self.xi)
+    }
+}
+
+impl Display for Fp6 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}) + ({})v + ({})v^2", self.c0, self.c1, self.c2)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::felt::big_uint::BigUint;
+
+    fn element(coefficients: [u64; 6]) -> Fp6 {
+        let p =
+            BigUint::from_hex("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47")
+                .unwrap();
+        let fp2 = |i: usize| BigFp2::from_u64(coefficients[i], coefficients[i + 1], p);
+        Fp6::new(fp2(0), fp2(2), fp2(4), BigFp2::from_u64(9, 1, p))
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let x = element([1, 2, 3, 4, 5, 6]);
+        let y = element([7, 0, 8, 9, 0, 10]);
+        let z = element([11, 12, 13, 14, 15, 16]);
+        assert_eq!(x * y, y * x);
+        assert_eq!(x * (y + z), x * y + x * z);
+        assert_eq!((x * y) * z, x * (y * z));
+        assert_eq!(x - y + y, x);
+        assert!((x + -x).is_zero());
+    }
+
+    #[test]
+    fn test_v_cubed_is_xi() {
+        let v = element([0, 0, 1, 0, 0, 0]);
+        let xi = Fp6::from_fp2(v.xi(), v.xi());
+        assert_eq!(v * v * v, xi);
+        assert_eq!(v.mul_by_v().mul_by_v(), xi);
+        let x = element([1, 2, 3, 4, 5, 6]);
+        assert_eq!(x.mul_by_v(), x * v);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let x = element([1, 2, 3, 4, 5, 6]);
+        assert_eq!(x * x.inverse().unwrap(), Fp6::one(x.xi()));
+        assert!(element([0; 6]).inverse().is_err());
+    }
+}
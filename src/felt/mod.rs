@@ -1,2 +1,13 @@
+pub mod big_felt;
+pub mod big_fp2;
+pub mod big_uint;
+pub mod fe25519;
 pub mod felt;
 pub mod felt_errors;
+pub mod fp12;
+pub mod fp2;
+pub mod fp6;
+pub mod ntt;
+pub(crate) mod parser;
+pub mod polynomial;
+pub mod primes;
@@ -0,0 +1,285 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use super::felt::Felt;
+
+thread_local! {
+    // The largest transform built so far for each modulus, see Ntt::shared
+    static SHARED: RefCell<HashMap<u64, Rc<Ntt>>> = RefCell::new(HashMap::new());
+}
+
+// Number theoretic transforms of length up to n = 2^k over F_p for
+// NTT-friendly primes p = c 2^s + 1 with k <= s, so that F_p has a primitive
+// n-th root of unity w. The powers of w are computed once, shorter
+// transforms of length m use every (n / m)-th of them.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Ntt {
+    modulus: u64,
+    size: usize,
+    root: Felt,
+    roots: Vec<Felt>,
+    inverse_roots: Vec<Felt>,
+}
+
+impl Ntt {
+    // None unless size is a power of two dividing p - 1
+    pub fn new(modulus: u64, size: usize) -> Option<Self> {
+        if modulus < 3 || !size.is_power_of_two() || !(modulus - 1).is_multiple_of(size as u64) {
+            return None;
+        }
+        let root = primitive_root_of_unity(modulus, size as u64)?;
+        let inverse_root = root.inverse().ok()?;
+        let powers = |base: Felt| {
+            (0..size / 2)
+                .scan(Felt::new(1, modulus), |power, _| {
+                    let current = *power;
+                    *power = *power * base;
+                    Some(current)
+                })
+                .collect()
+        };
+        Some(Ntt {
+            modulus,
+            size,
+            root,
+            roots: powers(root),
+            inverse_roots: powers(inverse_root),
+        })
+    }
+
+    // A transform of at least this size shared per thread, so repeated
+    // products over the same field do not recompute the roots of unity
+    pub fn shared(modulus: u64, size: usize) -> Option<Rc<Self>> {
+        SHARED.with(|shared| {
+            let mut shared = shared.borrow_mut();
+            if let Some(ntt) = shared.get(&modulus).filter(|ntt| ntt.size >= size) {
+                return Some(Rc::clone(ntt));
+            }
+            let ntt = Rc::new(Ntt::new(modulus, size)?);
+            shared.insert(modulus, Rc::clone(&ntt));
+            Some(ntt)
+        })
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    // The primitive n-th root of unity w, transforms of length m evaluate at
+    // the powers of w^(n / m)
+    pub fn root(&self) -> Felt {
+        self.root
+    }
+
+    // values[i] becomes sum_j values[j] v^(ij) for the primitive m-th root of
+    // unity v, the evaluations of the polynomial with these coefficients at
+    // the powers of v. The length m must be a power of two up to n.
+    pub fn forward(&self, values: &mut [Felt]) {
+        self.transform(values, &self.roots);
+    }
+
+    // The inverse of forward, interpolating from evaluations at powers of v
+    pub fn inverse(&self, values: &mut [Felt]) {
+        self.transform(values, &self.inverse_roots);
+        let length_inverse = Felt::new(values.len() as u64, self.modulus)
+            .inverse()
+            .unwrap();
+        for value in values.iter_mut() {
+            *value = *value * length_inverse;
+        }
+    }
+
+    // The product of two polynomials given by their coefficients, with a
+    // transform of the smallest length that holds it
+    pub fn multiply(&self, f: &[Felt], g: &[Felt]) -> Vec<Felt> {
+        if f.is_empty() || g.is_empty() {
+            return vec![];
+        }
+        let length = f.len() + g.len() - 1;
+        if length > self.size {
+            panic!(
+                "The product has {} coefficients but the transform holds at most {}",
+                length, self.size
+            );
+        }
+
+        let zero = Felt::new(0, self.modulus);
+        let pad = |values: &[Felt]| {
+            let mut padded = values.to_vec();
+            padded.resize(length.next_power_of_two(), zero);
+            self.forward(&mut padded);
+            padded
+        };
+        let (f, g) = (pad(f), pad(g));
+        let mut product: Vec<Felt> = f.iter().zip(&g).map(|(&x, &y)| x * y).collect();
+        self.inverse(&mut product);
+        product.truncate(length);
+        product
+    }
+
+    // Iterative Cooley-Tukey: bit-reversal permutation, then butterflies of
+    // length 2, 4, ..., m. The butterflies of length l need the l-th roots of
+    // unity, every (n / l)-th entry of the table.
+    fn transform(&self, values: &mut [Felt], roots: &[Felt]) {
+        let n = values.len();
+        if !n.is_power_of_two() || n > self.size {
+            panic!(
+                "Expected a power of two up to {} values but got {}",
+                self.size, n
+            );
+        }
+        if values.iter().any(|value| value.modulus() != self.modulus) {
+            panic!("Values must be in the field of the transform");
+        }
+
+        let bits = n.trailing_zeros();
+        for i in 0..n {
+            let j = i
+                .reverse_bits()
+                .checked_shr(usize::BITS - bits)
+                .unwrap_or(0);
+            if i < j {
+                values.swap(i, j);
+            }
+        }
+
+        let mut length = 2;
+        while length <= n {
+            let stride = self.size / length;
+            for start in (0..n).step_by(length) {
+                for k in 0..length / 2 {
+                    let even = values[start + k];
+                    let odd = values[start + k + length / 2] * roots[k * stride];
+                    values[start + k] = even + odd;
+                    values[start + k + length / 2] = even - odd;
+                }
+            }
+            length *= 2;
+        }
+    }
+}
+
+// The largest power of two dividing p - 1, the longest transform F_p supports
+pub fn max_ntt_size(modulus: u64) -> u64 {
+    1 << (modulus - 1).trailing_zeros()
+}
+
+// z^((p - 1) / n) for a non-residue z has order exactly n when n is a power
+// of two dividing p - 1
+fn primitive_root_of_unity(modulus: u64, n: u64) -> Option<Felt> {
+    let non_residue = Felt::smallest_nonresidue(modulus)?;
+    Some(non_residue.pow((modulus - 1) / n))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 119 * 2^23 + 1
+    const P: u64 = 998244353;
+
+    fn felts(values: &[u64], modulus: u64) -> Vec<Felt> {
+        values.iter().map(|&v| Felt::new(v, modulus)).collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(max_ntt_size(P), 1 << 23);
+        assert!(Ntt::new(P, 1 << 24).is_none());
+        assert!(Ntt::new(P, 12).is_none());
+        assert!(Ntt::new(43, 4).is_none());
+        assert_eq!(max_ntt_size(43), 2);
+        assert_eq!(max_ntt_size(12289), 1 << 12);
+        let ntt = Ntt::new(12289, 1 << 12).unwrap();
+        assert_eq!(ntt.size(), 1 << 12);
+        assert_eq!(ntt.root().pow(1 << 12).value(), 1);
+        assert_eq!(ntt.root().pow(1 << 11).value(), 12288);
+    }
+
+    // The transform evaluates at the powers of w
+    #[test]
+    fn test_forward_is_evaluation() {
+        let ntt = Ntt::new(P, 8).unwrap();
+        let coefficients = felts(&[3, 1, 4, 1, 5, 9, 2, 6], P);
+        let mut values = coefficients.clone();
+        ntt.forward(&mut values);
+        for (i, value) in values.iter().enumerate() {
+            let x = ntt.root().pow(i as u64);
+            let expected = coefficients
+                .iter()
+                .rev()
+                .fold(Felt::new(0, P), |acc, &c| acc * x + c);
+            assert_eq!(*value, expected, "{}", i);
+        }
+        ntt.inverse(&mut values);
+        assert_eq!(values, coefficients);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for log_size in 0..=10 {
+            let ntt = Ntt::new(12289, 1 << log_size).unwrap();
+            let original: Vec<Felt> = (0..1u64 << log_size)
+                .map(|i| Felt::new(i * i + 7 * i + 1, 12289))
+                .collect();
+            let mut values = original.clone();
+            ntt.forward(&mut values);
+            ntt.inverse(&mut values);
+            assert_eq!(values, original, "{}", log_size);
+        }
+    }
+
+    // A transform of length 4 from the table for 16 matches a fresh one
+    #[test]
+    fn test_shorter_transforms() {
+        let (long, short) = (Ntt::new(P, 16).unwrap(), Ntt::new(P, 4).unwrap());
+        assert_eq!(long.root().pow(4), short.root());
+        let mut values = felts(&[3, 1, 4, 1], P);
+        let mut expected = values.clone();
+        long.forward(&mut values);
+        short.forward(&mut expected);
+        assert_eq!(values, expected);
+        long.inverse(&mut values);
+        assert_eq!(values, felts(&[3, 1, 4, 1], P));
+    }
+
+    #[test]
+    fn test_multiply() {
+        let ntt = Ntt::new(P, 8).unwrap();
+        // (1 + 2x + 3x^2)(4 + 5x) = 4 + 13x + 22x^2 + 15x^3
+        let product = ntt.multiply(&felts(&[1, 2, 3], P), &felts(&[4, 5], P));
+        assert_eq!(product, felts(&[4, 13, 22, 15], P));
+        // x^3 * x^4 = x^7 just fits
+        let product = ntt.multiply(&felts(&[0, 0, 0, 1], P), &felts(&[0, 0, 0, 0, 1], P));
+        assert_eq!(product, felts(&[0, 0, 0, 0, 0, 0, 0, 1], P));
+        assert!(ntt.multiply(&[], &felts(&[1], P)).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "The product has 9 coefficients but the transform holds at most 8")]
+    fn test_multiply_too_long_should_panic() {
+        let ntt = Ntt::new(P, 8).unwrap();
+        ntt.multiply(&felts(&[0, 0, 0, 1], P), &felts(&[0, 0, 0, 0, 0, 1], P));
+    }
+
+    #[test]
+    fn test_shared() {
+        let ntt = Ntt::shared(P, 8).unwrap();
+        assert!(Rc::ptr_eq(&ntt, &Ntt::shared(P, 4).unwrap()));
+        let larger = Ntt::shared(P, 16).unwrap();
+        assert!(larger.size() >= 16);
+        assert!(Rc::ptr_eq(&larger, &Ntt::shared(P, 8).unwrap()));
+        assert!(Ntt::shared(43, 4).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a power of two up to 8 values but got 5")]
+    fn test_wrong_length_should_panic() {
+        Ntt::new(P, 8)
+            .unwrap()
+            .forward(&mut felts(&[1, 2, 3, 4, 5], P));
+    }
+}
@@ -0,0 +1,135 @@
+use super::felt::Felt;
+
+// Cursor over a string shared by the FromStr implementations. Errors are
+// (byte offset, message) pairs that the callers wrap in their own error type.
+pub(crate) struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+pub(crate) type ParseResult<T> = Result<T, (usize, String)>;
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Parser { input, position: 0 }
+    }
+
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    pub(crate) fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    pub(crate) fn error<T>(&self, position: usize, message: &str) -> ParseResult<T> {
+        Err((position, message.to_string()))
+    }
+
+    pub(crate) fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    // Consumes the token (after optional whitespace) if it comes next
+    pub(crate) fn consume(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn expect(&mut self, token: &str) -> ParseResult<()> {
+        if self.consume(token) {
+            Ok(())
+        } else {
+            self.error(self.position, &format!("expected \"{}\"", token))
+        }
+    }
+
+    pub(crate) fn expect_end(&mut self) -> ParseResult<()> {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            self.error(self.position, "unexpected trailing input")
+        }
+    }
+
+    // Decimal or 0x-prefixed hexadecimal integer with an optional sign,
+    // returns the start offset, the sign and the absolute value
+    pub(crate) fn integer(&mut self) -> ParseResult<(usize, bool, u64)> {
+        self.skip_whitespace();
+        let start = self.position;
+        let negative = self.rest().starts_with('-');
+        if negative {
+            self.position += 1;
+        }
+
+        // "0x" only starts a hex literal when a hex digit follows, so that a
+        // term like "0x + 7" reads as the coefficient 0
+        let rest = self.rest();
+        let is_hex =
+            rest.starts_with("0x") && rest[2..].starts_with(|c: char| c.is_ascii_hexdigit());
+        let (radix, digits_start) = if is_hex {
+            (16, self.position + 2)
+        } else {
+            (10, self.position)
+        };
+        let digits = self.input[digits_start..]
+            .chars()
+            .take_while(|c| c.is_digit(radix))
+            .count();
+        if digits == 0 {
+            return self.error(start, "expected an integer");
+        }
+
+        let end = digits_start + digits;
+        let value = match u64::from_str_radix(&self.input[digits_start..end], radix) {
+            Ok(value) => value,
+            Err(_) => return self.error(start, "integer does not fit in 64 bits"),
+        };
+        self.position = end;
+        Ok((start, negative, value))
+    }
+
+    // "(mod m)" with m > 0
+    pub(crate) fn modulus(&mut self) -> ParseResult<u64> {
+        self.expect("(")?;
+        self.expect("mod")?;
+        let (start, negative, modulus) = self.integer()?;
+        if negative || modulus == 0 {
+            return self.error(start, "modulus must be positive");
+        }
+        self.expect(")")?;
+        Ok(modulus)
+    }
+
+    // Curve names from the registry, letters, digits, '-' and '_'
+    pub(crate) fn identifier(&mut self) -> ParseResult<(usize, &'a str)> {
+        self.skip_whitespace();
+        let start = self.position;
+        let length = self
+            .rest()
+            .chars()
+            .take_while(|&c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            .count();
+        if length == 0 {
+            return self.error(start, "expected a curve name");
+        }
+        self.position += length;
+        Ok((start, &self.input[start..self.position]))
+    }
+}
+
+pub(crate) fn signed_felt(negative: bool, value: u64, modulus: u64) -> Felt {
+    let felt = Felt::new(value, modulus);
+    if negative {
+        -felt
+    } else {
+        felt
+    }
+}
@@ -0,0 +1,775 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use rand::Rng;
+
+use super::{felt::Felt, ntt::Ntt};
+
+// Below this many coefficients in the smaller factor schoolbook
+// multiplication beats the transforms
+const NTT_THRESHOLD: usize = 64;
+
+// Dense univariate polynomial over F_p, coefficients from the constant term
+// up without trailing zeros, so the zero polynomial has none
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Polynomial {
+    coefficients: Vec<Felt>,
+    modulus: u64,
+}
+
+impl Polynomial {
+    pub fn new(coefficients: Vec<Felt>, modulus: u64) -> Self {
+        if coefficients.iter().any(|c| c.modulus() != modulus) {
+            panic!("Coefficients must be in the field of the polynomial");
+        }
+        let mut polynomial = Polynomial {
+            coefficients,
+            modulus,
+        };
+        polynomial.trim();
+        polynomial
+    }
+
+    pub fn from_u64(coefficients: &[u64], modulus: u64) -> Self {
+        let coefficients = coefficients
+            .iter()
+            .map(|&c| Felt::new(c, modulus))
+            .collect();
+        Polynomial::new(coefficients, modulus)
+    }
+
+    pub fn zero(modulus: u64) -> Self {
+        Polynomial::new(vec![], modulus)
+    }
+
+    pub fn constant(c: Felt) -> Self {
+        Polynomial::new(vec![c], c.modulus())
+    }
+
+    pub fn one(modulus: u64) -> Self {
+        Polynomial::constant(Felt::new(1, modulus))
+    }
+
+    // c x^degree
+    pub fn monomial(c: Felt, degree: usize) -> Self {
+        let mut coefficients = vec![Felt::new(0, c.modulus()); degree + 1];
+        coefficients[degree] = c;
+        Polynomial::new(coefficients, c.modulus())
+    }
+
+    // The polynomial x
+    pub fn x(modulus: u64) -> Self {
+        Polynomial::monomial(Felt::new(1, modulus), 1)
+    }
+
+    pub fn coefficients(&self) -> &[Felt] {
+        &self.coefficients
+    }
+
+    // Coefficient of x^i, zero beyond the degree
+    pub fn coefficient(&self, i: usize) -> Felt {
+        self.coefficients
+            .get(i)
+            .copied()
+            .unwrap_or(Felt::new(0, self.modulus))
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    // None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn leading_coefficient(&self) -> Option<Felt> {
+        self.coefficients.last().copied()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        *self == Polynomial::one(self.modulus)
+    }
+
+    // Divided by the leading coefficient, the zero polynomial stays zero
+    pub fn monic(&self) -> Self {
+        match self.leading_coefficient() {
+            Some(lead) => self.scale(lead.inverse().unwrap()),
+            None => self.clone(),
+        }
+    }
+
+    pub fn scale(&self, c: Felt) -> Self {
+        let coefficients = self.coefficients.iter().map(|&x| x * c).collect();
+        Polynomial::new(coefficients, self.modulus)
+    }
+
+    // Horner's method
+    pub fn evaluate(&self, x: Felt) -> Felt {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Felt::new(0, self.modulus), |acc, &c| acc * x + c)
+    }
+
+    pub fn derivative(&self) -> Self {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &c)| Felt::new(i as u64, self.modulus) * c)
+            .collect();
+        Polynomial::new(coefficients, self.modulus)
+    }
+
+    // Quotient and remainder of long division, deg r < deg divisor
+    pub fn div_rem(&self, divisor: &Polynomial) -> (Self, Self) {
+        self.check_field(divisor, "divide");
+        let divisor_degree = match divisor.degree() {
+            Some(degree) => degree,
+            None => panic!("Cannot divide by the zero polynomial"),
+        };
+        let lead_inverse = divisor.leading_coefficient().unwrap().inverse().unwrap();
+
+        let mut remainder = self.coefficients.clone();
+        let zero = Felt::new(0, self.modulus);
+        let mut quotient = vec![zero; remainder.len().saturating_sub(divisor_degree)];
+        for shift in (0..quotient.len()).rev() {
+            let c = remainder[shift + divisor_degree] * lead_inverse;
+            quotient[shift] = c;
+            for (i, &d) in divisor.coefficients.iter().enumerate() {
+                remainder[shift + i] = remainder[shift + i] - c * d;
+            }
+        }
+        remainder.truncate(divisor_degree);
+        (
+            Polynomial::new(quotient, self.modulus),
+            Polynomial::new(remainder, self.modulus),
+        )
+    }
+
+    // Monic greatest common divisor, zero only if both are zero
+    pub fn gcd(&self, other: &Polynomial) -> Self {
+        self.check_field(other, "take the gcd of");
+        let (mut f, mut g) = (self.clone(), other.clone());
+        while !g.is_zero() {
+            let remainder = f.div_rem(&g).1;
+            f = g;
+            g = remainder;
+        }
+        f.monic()
+    }
+
+    // self^exponent mod modulus_polynomial, by square and multiply
+    pub fn pow_mod(&self, exponent: u64, modulus_polynomial: &Polynomial) -> Self {
+        let reduce = |f: &Polynomial| f.div_rem(modulus_polynomial).1;
+        let base = reduce(self);
+        let mut result = reduce(&Polynomial::one(self.modulus));
+        for i in (0..u64::BITS - exponent.leading_zeros()).rev() {
+            result = reduce(&(&result * &result));
+            if (exponent >> i) & 1 == 1 {
+                result = reduce(&(&result * &base));
+            }
+        }
+        result
+    }
+
+    // The Lagrange polynomial of degree < n through n points with distinct x
+    pub fn interpolate(points: &[(Felt, Felt)]) -> Self {
+        let modulus = match points.first() {
+            Some((x, _)) => x.modulus(),
+            None => panic!("Cannot interpolate without points"),
+        };
+        let mut result = Polynomial::zero(modulus);
+        for (i, &(xi, yi)) in points.iter().enumerate() {
+            let mut basis = Polynomial::one(modulus);
+            let mut denominator = Felt::new(1, modulus);
+            for (j, &(xj, _)) in points.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                if xi == xj {
+                    panic!("Interpolation points must have distinct x coordinates");
+                }
+                basis = &basis * &Polynomial::new(vec![-xj, Felt::new(1, modulus)], modulus);
+                denominator = denominator * (xi - xj);
+            }
+            result = &result + &basis.scale(yi / denominator);
+        }
+        result
+    }
+
+    // Monic squarefree factors g_i with f = lead * prod g_i^i. Yun's algorithm
+    // misses factors whose multiplicity is a multiple of p, what is left is a
+    // p-th power h(x)^p = h(x^p) whose p-th root is factored again.
+    pub fn squarefree_factorization(&self) -> Vec<(Polynomial, u32)> {
+        assert!(!self.is_zero(), "Cannot factor the zero polynomial");
+        let mut factors = Vec::new();
+        let f = self.monic();
+        let mut c = f.gcd(&f.derivative());
+        let mut w = f.div_rem(&c).0;
+        let mut i = 1;
+        while !w.is_one() {
+            let y = w.gcd(&c);
+            let factor = w.div_rem(&y).0;
+            if !factor.is_one() {
+                factors.push((factor, i));
+            }
+            c = c.div_rem(&y).0;
+            w = y;
+            i += 1;
+        }
+
+        if !c.is_one() {
+            // c(x) = h(x^p) and h^p = h(x^p) since a^p = a in F_p
+            let p = self.modulus as usize;
+            let root_coefficients = c.coefficients.iter().step_by(p).copied().collect();
+            let root = Polynomial::new(root_coefficients, self.modulus);
+            for (factor, multiplicity) in root.squarefree_factorization() {
+                factors.push((factor, multiplicity * self.modulus as u32));
+            }
+        }
+
+        factors.sort_by_key(|(_, multiplicity)| *multiplicity);
+        factors
+    }
+
+    // Splits a squarefree polynomial into (g_d, d) where g_d is the product
+    // of its monic irreducible factors of degree d
+    pub fn distinct_degree_factorization(&self) -> Vec<(Polynomial, usize)> {
+        let mut factors = Vec::new();
+        let mut f = self.monic();
+        let x = Polynomial::x(self.modulus);
+        let mut h = x.clone();
+        let mut d = 1;
+        while f.degree().unwrap_or(0) >= 2 * d {
+            // h = x^(p^d) mod f, whose gcd with x^(p^d) - x collects the
+            // irreducible factors of degree dividing d
+            h = h.pow_mod(self.modulus, &f);
+            let g = f.gcd(&(&h - &x));
+            if !g.is_one() {
+                f = f.div_rem(&g).0;
+                h = h.div_rem(&f).1;
+                factors.push((g, d));
+            }
+            d += 1;
+        }
+        if let Some(degree) = f.degree().filter(|&degree| degree > 0) {
+            factors.push((f, degree));
+        }
+        factors
+    }
+
+    // Cantor-Zassenhaus: splits a product of distinct monic irreducible
+    // polynomials of degree d with gcds against random a^((p^d - 1) / 2) - 1,
+    // or the trace a + a^2 + ... + a^(2^(d - 1)) over F_2. Other inputs would
+    // never split, so they are checked first and panic.
+    pub fn equal_degree_factorization<R: Rng + ?Sized>(
+        &self,
+        degree: usize,
+        rng: &mut R,
+    ) -> Vec<Polynomial> {
+        let f = self.monic();
+        let n = f.degree().unwrap_or(0);
+        if n == 0 {
+            return vec![];
+        }
+        assert!(
+            n.is_multiple_of(degree),
+            "The degree must divide the degree of the polynomial"
+        );
+        let squarefree = f.gcd(&f.derivative()).is_one();
+        assert!(
+            squarefree && f.distinct_degree_factorization() == vec![(f.clone(), degree)],
+            "The polynomial must be a product of distinct irreducible factors of degree {}",
+            degree
+        );
+        f.split_equal_degree(degree, rng)
+    }
+
+    // Monic irreducible factors with multiplicities, sorted by degree and
+    // then coefficients
+    pub fn factor<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<(Polynomial, u32)> {
+        let mut factors = Vec::new();
+        for (squarefree, multiplicity) in self.squarefree_factorization() {
+            for (product, degree) in squarefree.distinct_degree_factorization() {
+                for factor in product.split_equal_degree(degree, rng) {
+                    factors.push((factor, multiplicity));
+                }
+            }
+        }
+        factors.sort_by_key(|(factor, _)| {
+            let values: Vec<u64> = factor
+                .coefficients
+                .iter()
+                .rev()
+                .map(|c| c.value())
+                .collect();
+            (factor.degree(), values)
+        });
+        factors
+    }
+
+    // The distinct roots in F_p, sorted. gcd(f, x^p - x) is the product of
+    // x - r over the roots r, split with Cantor-Zassenhaus.
+    pub fn roots<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<Felt> {
+        assert!(
+            !self.is_zero(),
+            "Cannot find the roots of the zero polynomial"
+        );
+        let x = Polynomial::x(self.modulus);
+        let f = self.monic();
+        let linear_factors = f.gcd(&(&x.pow_mod(self.modulus, &f) - &x));
+
+        let mut roots: Vec<Felt> = linear_factors
+            .split_equal_degree(1, rng)
+            .into_iter()
+            .map(|factor| -factor.coefficient(0))
+            .collect();
+        roots.sort_by_key(|root| root.value());
+        roots
+    }
+
+    // equal_degree_factorization without checking its input
+    fn split_equal_degree<R: Rng + ?Sized>(&self, degree: usize, rng: &mut R) -> Vec<Polynomial> {
+        let f = self.monic();
+        let n = f.degree().unwrap_or(0);
+        if n == 0 {
+            return vec![];
+        }
+        if n == degree {
+            return vec![f];
+        }
+
+        let p = self.modulus;
+        loop {
+            let random: Vec<Felt> = (0..n).map(|_| Felt::new(rng.gen_range(0..p), p)).collect();
+            let a = Polynomial::new(random, p);
+            if a.degree().unwrap_or(0) == 0 {
+                continue;
+            }
+
+            let mut candidate = f.gcd(&a);
+            if candidate.is_one() {
+                let b = if p == 2 {
+                    let mut power = a.clone();
+                    let mut trace = a.clone();
+                    for _ in 1..degree {
+                        power = power.pow_mod(2, &f);
+                        trace = &trace + &power;
+                    }
+                    trace
+                } else {
+                    // a^((p^d - 1) / 2) = (a^(1 + p + ... + p^(d - 1)))^((p - 1) / 2)
+                    let mut power = a.div_rem(&f).1;
+                    let mut norm = power.clone();
+                    for _ in 1..degree {
+                        power = power.pow_mod(p, &f);
+                        norm = (&norm * &power).div_rem(&f).1;
+                    }
+                    &norm.pow_mod((p - 1) / 2, &f) - &Polynomial::one(p)
+                };
+                candidate = f.gcd(&b);
+            }
+
+            let candidate_degree = candidate.degree().unwrap_or(0);
+            if candidate_degree > 0 && candidate_degree < n {
+                let mut factors = candidate.split_equal_degree(degree, rng);
+                factors.extend(f.div_rem(&candidate).0.split_equal_degree(degree, rng));
+                return factors;
+            }
+        }
+    }
+
+    fn trim(&mut self) {
+        while self.coefficients.last().is_some_and(|c| c.value() == 0) {
+            self.coefficients.pop();
+        }
+    }
+
+    fn mul_schoolbook(&self, other: &Polynomial) -> Polynomial {
+        let zero = Felt::new(0, self.modulus);
+        let mut product = vec![zero; self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, &x) in self.coefficients.iter().enumerate() {
+            for (j, &y) in other.coefficients.iter().enumerate() {
+                product[i + j] = product[i + j] + x * y;
+            }
+        }
+        Polynomial::new(product, self.modulus)
+    }
+
+    fn check_field(&self, other: &Polynomial, operation: &str) {
+        if self.modulus != other.modulus {
+            panic!("Cannot {} two polynomials with different moduli", operation);
+        }
+    }
+}
+
+impl Add<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: &Polynomial) -> Polynomial {
+        self.check_field(other, "add");
+        let length = self.coefficients.len().max(other.coefficients.len());
+        let coefficients = (0..length)
+            .map(|i| self.coefficient(i) + other.coefficient(i))
+            .collect();
+        Polynomial::new(coefficients, self.modulus)
+    }
+}
+
+impl Sub<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, other: &Polynomial) -> Polynomial {
+        self.check_field(other, "subtract");
+        let length = self.coefficients.len().max(other.coefficients.len());
+        let coefficients = (0..length)
+            .map(|i| self.coefficient(i) - other.coefficient(i))
+            .collect();
+        Polynomial::new(coefficients, self.modulus)
+    }
+}
+
+impl Mul<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    // NTT multiplication for large factors when F_p has a root of unity of
+    // high enough order, schoolbook otherwise
+    fn mul(self, other: &Polynomial) -> Polynomial {
+        self.check_field(other, "multiply");
+        if self.is_zero() || other.is_zero() {
+            return Polynomial::zero(self.modulus);
+        }
+        let length = self.coefficients.len() + other.coefficients.len() - 1;
+        if self.coefficients.len().min(other.coefficients.len()) >= NTT_THRESHOLD {
+            if let Some(ntt) = Ntt::shared(self.modulus, length.next_power_of_two()) {
+                let product = ntt.multiply(&self.coefficients, &other.coefficients);
+                return Polynomial::new(product, self.modulus);
+            }
+        }
+        self.mul_schoolbook(other)
+    }
+}
+
+impl Add for Polynomial {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl Sub for Polynomial {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        &self - &other
+    }
+}
+
+impl Mul for Polynomial {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+impl Neg for Polynomial {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let coefficients = self.coefficients.iter().map(|&c| -c).collect();
+        Polynomial::new(coefficients, self.modulus)
+    }
+}
+
+// Highest degree first, e.g. "x^3 + 42x + 5 (mod 43)"
+impl Display for Polynomial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0 (mod {})", self.modulus);
+        }
+        let mut terms = Vec::new();
+        for (i, c) in self.coefficients.iter().enumerate().rev() {
+            let c = c.value();
+            let coefficient = if c == 1 && i > 0 {
+                String::new()
+            } else {
+                c.to_string()
+            };
+            match (c, i) {
+                (0, _) => continue,
+                (_, 0) => terms.push(coefficient),
+                (_, 1) => terms.push(format!("{}x", coefficient)),
+                _ => terms.push(format!("{}x^{}", coefficient, i)),
+            }
+        }
+        write!(f, "{} (mod {})", terms.join(" + "), self.modulus)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn polynomial(coefficients: &[u64]) -> Polynomial {
+        Polynomial::from_u64(coefficients, 43)
+    }
+
+    fn product(factors: &[(Polynomial, u32)]) -> Polynomial {
+        factors.iter().fold(polynomial(&[1]), |acc, (f, m)| {
+            (0..*m).fold(acc, |acc, _| &acc * f)
+        })
+    }
+
+    #[test]
+    fn test_new_trims_zeros() {
+        let f = polynomial(&[1, 2, 0, 43]);
+        assert_eq!(f.coefficients(), polynomial(&[1, 2]).coefficients());
+        assert_eq!(f.degree(), Some(1));
+        assert_eq!(polynomial(&[0, 0]).degree(), None);
+        assert!(polynomial(&[]).is_zero());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let f = polynomial(&[1, 2, 3]);
+        let g = polynomial(&[5, 0, 40, 1]);
+        // (3x^2 + 2x + 1)(x^3 + 40x^2 + 5)
+        assert_eq!(&f * &g, polynomial(&[5, 10, 12, 38, 36, 3]));
+        assert_eq!(&f + &g, polynomial(&[6, 2, 0, 1]));
+        assert_eq!(f.clone() - f.clone(), Polynomial::zero(43));
+        assert_eq!(-f.clone() + f.clone(), Polynomial::zero(43));
+        assert_eq!(&f * &Polynomial::zero(43), Polynomial::zero(43));
+    }
+
+    // Factors above the threshold over an NTT-friendly prime go through the
+    // transform, the result must match schoolbook multiplication
+    #[test]
+    fn test_ntt_multiplication() {
+        let modulus = 998244353;
+        let mut rng = StdRng::seed_from_u64(50);
+        for (m, n) in [(64, 64), (100, 300), (257, 129)] {
+            let mut random = |length: usize| {
+                let coefficients: Vec<u64> =
+                    (0..length).map(|_| rng.gen_range(1..modulus)).collect();
+                Polynomial::from_u64(&coefficients, modulus)
+            };
+            let (f, g) = (random(m), random(n));
+            let product = &f * &g;
+            assert_eq!(product.degree(), Some(m + n - 2));
+            assert_eq!(product, f.mul_schoolbook(&g));
+        }
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let f = polynomial(&[5, 10, 55, 84, 122, 3, 7]);
+        let g = polynomial(&[1, 2, 3]);
+        let (q, r) = f.div_rem(&g);
+        assert!(r.degree() < g.degree());
+        assert_eq!(&(&q * &g) + &r, f);
+        assert_eq!(g.div_rem(&f), (Polynomial::zero(43), g.clone()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide by the zero polynomial")]
+    fn test_div_by_zero_should_panic() {
+        polynomial(&[1, 2]).div_rem(&Polynomial::zero(43));
+    }
+
+    #[test]
+    fn test_gcd() {
+        let common = polynomial(&[3, 1]);
+        let f = &common * &polynomial(&[1, 0, 1]);
+        let g = &common * &polynomial(&[7, 5]);
+        assert_eq!(f.gcd(&g), common);
+        assert_eq!(f.scale(Felt::new(5, 43)).gcd(&f), f.monic());
+        assert!(polynomial(&[1, 0, 1]).gcd(&polynomial(&[7, 5])).is_one());
+        assert_eq!(f.gcd(&Polynomial::zero(43)), f.monic());
+    }
+
+    #[test]
+    fn test_evaluate_and_derivative() {
+        let f = polynomial(&[1, 2, 3]);
+        assert_eq!(f.evaluate(Felt::new(2, 43)), Felt::new(17, 43));
+        assert_eq!(f.derivative(), polynomial(&[2, 6]));
+        // x^43 has derivative 43x^42 = 0
+        let x_to_the_p = Polynomial::monomial(Felt::new(1, 43), 43);
+        assert!(x_to_the_p.derivative().is_zero());
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        // x^43 = x (mod x^43 - x), and x^p = x mod any polynomial with all
+        // of F_p as roots
+        let x = Polynomial::x(43);
+        let f = polynomial(&[3, 1, 4, 1, 5]);
+        let expected = (0..43)
+            .fold(polynomial(&[1]), |acc, _| &acc * &x)
+            .div_rem(&f)
+            .1;
+        assert_eq!(x.pow_mod(43, &f), expected);
+        assert_eq!(x.pow_mod(0, &f), polynomial(&[1]));
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let f = polynomial(&[7, 0, 5, 1]);
+        let points: Vec<(Felt, Felt)> = [1, 4, 9, 30]
+            .iter()
+            .map(|&x| (Felt::new(x, 43), f.evaluate(Felt::new(x, 43))))
+            .collect();
+        assert_eq!(Polynomial::interpolate(&points), f);
+        assert_eq!(
+            Polynomial::interpolate(&points[..1]),
+            Polynomial::constant(points[0].1)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Interpolation points must have distinct x coordinates")]
+    fn test_interpolate_repeated_x_should_panic() {
+        let felt = |v| Felt::new(v, 43);
+        Polynomial::interpolate(&[(felt(1), felt(2)), (felt(1), felt(3))]);
+    }
+
+    #[test]
+    fn test_squarefree_factorization() {
+        let a = polynomial(&[1, 1]);
+        let b = polynomial(&[2, 0, 1]);
+        let c = polynomial(&[5, 1]);
+        // 3 (x + 1) (x^2 + 2)^2 (x + 5)^3
+        let factors = [(a.clone(), 1), (b.clone(), 2), (c.clone(), 3)];
+        let f = product(&factors).scale(Felt::new(3, 43));
+        let expected = vec![(a, 1), (b, 2), (c, 3)];
+        assert_eq!(f.squarefree_factorization(), expected);
+    }
+
+    #[test]
+    fn test_squarefree_factorization_of_pth_powers() {
+        // (x + 1)^7 (x + 2)^8 over F_7, where (x + 1)^7 = x^7 + 1 has zero
+        // derivative
+        let a = Polynomial::from_u64(&[1, 1], 7);
+        let b = Polynomial::from_u64(&[2, 1], 7);
+        let f = (0..7).fold(Polynomial::one(7), |acc, _| &acc * &a);
+        let f = (0..8).fold(f, |acc, _| &acc * &b);
+        assert_eq!(f.squarefree_factorization(), vec![(a, 7), (b, 8)]);
+    }
+
+    #[test]
+    fn test_distinct_degree_factorization() {
+        // x^2 + 1, x^2 - 2 and x^3 + x + 3 are irreducible mod 43
+        let linear = &polynomial(&[1, 1]) * &polynomial(&[3, 1]);
+        let quadratic = &polynomial(&[1, 0, 1]) * &polynomial(&[41, 0, 1]);
+        let cubic = polynomial(&[3, 1, 0, 1]);
+        let f = &(&linear * &quadratic) * &cubic;
+        assert_eq!(
+            f.distinct_degree_factorization(),
+            vec![(linear, 1), (quadratic, 2), (cubic, 3)]
+        );
+    }
+
+    #[test]
+    fn test_equal_degree_factorization() {
+        let mut rng = StdRng::seed_from_u64(43);
+        let quadratic = &polynomial(&[1, 0, 1]) * &polynomial(&[41, 0, 1]);
+        let mut factors = quadratic.equal_degree_factorization(2, &mut rng);
+        factors.sort_by_key(|factor| factor.coefficient(0).value());
+        assert_eq!(
+            factors,
+            vec![polynomial(&[1, 0, 1]), polynomial(&[41, 0, 1])]
+        );
+    }
+
+    // x^2 + 1 is irreducible mod 43, so it has no linear factors to split off
+    #[test]
+    #[should_panic(
+        expected = "The polynomial must be a product of distinct irreducible factors of degree 1"
+    )]
+    fn test_equal_degree_factorization_of_irreducible_should_panic() {
+        let mut rng = StdRng::seed_from_u64(43);
+        polynomial(&[1, 0, 1]).equal_degree_factorization(1, &mut rng);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The polynomial must be a product of distinct irreducible factors of degree 1"
+    )]
+    fn test_equal_degree_factorization_of_square_should_panic() {
+        let mut rng = StdRng::seed_from_u64(43);
+        let square = &polynomial(&[1, 1]) * &polynomial(&[1, 1]);
+        square.equal_degree_factorization(1, &mut rng);
+    }
+
+    #[test]
+    fn test_factor() {
+        let mut rng = StdRng::seed_from_u64(43);
+        let factors = vec![
+            (polynomial(&[1, 1]), 2),
+            (polynomial(&[3, 1]), 1),
+            (polynomial(&[1, 0, 1]), 1),
+            (polynomial(&[41, 0, 1]), 3),
+            (polynomial(&[3, 1, 0, 1]), 1),
+        ];
+        let f = product(&factors).scale(Felt::new(7, 43));
+        assert_eq!(f.factor(&mut rng), factors);
+    }
+
+    #[test]
+    fn test_factor_over_f2() {
+        let mut rng = StdRng::seed_from_u64(2);
+        // x^2 + x + 1 and x^3 + x + 1 are irreducible over F_2
+        let a = Polynomial::from_u64(&[1, 1, 1], 2);
+        let b = Polynomial::from_u64(&[1, 1, 0, 1], 2);
+        let c = Polynomial::from_u64(&[1, 0, 1, 1], 2);
+        let f = &(&a * &b) * &c;
+        assert_eq!(f.factor(&mut rng), vec![(a, 1), (b, 1), (c, 1)]);
+    }
+
+    #[test]
+    fn test_roots() {
+        let mut rng = StdRng::seed_from_u64(43);
+        // (x - 1)(x - 5)^2 (x^2 + 1)
+        let f = product(&[
+            (polynomial(&[42, 1]), 1),
+            (polynomial(&[38, 1]), 2),
+            (polynomial(&[1, 0, 1]), 1),
+        ]);
+        let felt = |v| Felt::new(v, 43);
+        assert_eq!(f.roots(&mut rng), vec![felt(1), felt(5)]);
+        assert_eq!(polynomial(&[1, 0, 1]).roots(&mut rng), vec![]);
+        assert_eq!(polynomial(&[7]).roots(&mut rng), vec![]);
+
+        // x^43 - x vanishes on all of F_43
+        let all = &Polynomial::monomial(felt(1), 43) - &Polynomial::x(43);
+        assert_eq!(all.roots(&mut rng), (0..43).map(felt).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot find the roots of the zero polynomial")]
+    fn test_roots_of_zero_should_panic() {
+        Polynomial::zero(43).roots(&mut StdRng::seed_from_u64(0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            polynomial(&[5, 42, 0, 1]).to_string(),
+            "x^3 + 42x + 5 (mod 43)"
+        );
+        assert_eq!(polynomial(&[1, 1]).to_string(), "x + 1 (mod 43)");
+        assert_eq!(polynomial(&[0, 2]).to_string(), "2x (mod 43)");
+        assert_eq!(Polynomial::zero(43).to_string(), "0 (mod 43)");
+    }
+}
@@ -0,0 +1,225 @@
+// Miller-Rabin with the first 12 primes as bases, which is deterministic for
+// every n < 3.3 * 10^24 and so for all u64
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for p in BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    // n - 1 = d * 2^s with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'witness: for a in BASES {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+// Prime factorization as (prime, exponent) pairs in increasing order. Small
+// factors are found by trial division and the rest with Pollard's rho, so
+// any u64 factors quickly. 0 and 1 have no prime factors.
+pub fn prime_factors(n: u64) -> Vec<(u64, u32)> {
+    if n <= 1 {
+        return vec![];
+    }
+    let mut primes = Vec::new();
+    let mut n = n;
+    for d in 2..TRIAL_DIVISION_BOUND {
+        while n.is_multiple_of(d) {
+            n /= d;
+            primes.push(d);
+        }
+    }
+
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            primes.push(m);
+        } else {
+            let d = pollard_rho(m);
+            stack.push(d);
+            stack.push(m / d);
+        }
+    }
+
+    primes.sort_unstable();
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((q, exponent)) if *q == p => *exponent += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+// The largest prime dividing n, 1 for n <= 1
+pub fn largest_prime_factor(n: u64) -> u64 {
+    prime_factors(n).last().map_or(1, |&(p, _)| p)
+}
+
+// All positive divisors of n in increasing order
+pub fn divisors(n: u64) -> Vec<u64> {
+    let mut divisors = vec![1];
+    for (p, exponent) in prime_factors(n) {
+        let mut multiples = Vec::new();
+        for &d in divisors.iter() {
+            let mut power = d;
+            for _ in 0..exponent {
+                power *= p;
+                multiples.push(power);
+            }
+        }
+        divisors.extend(multiples);
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+const TRIAL_DIVISION_BOUND: u64 = 1000;
+
+// A nontrivial divisor of a composite n without factors below the trial
+// division bound, using Brent's cycle detection on x -> x^2 + c
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1_u64.. {
+        let f = |x: u64| ((mul_mod(x, x, n) as u128 + c as u128) % n as u128) as u64;
+        let (mut x, mut y) = (2, 2);
+        let mut power = 1;
+        let mut steps = 0;
+        let d = loop {
+            if steps == power {
+                x = y;
+                power *= 2;
+                steps = 0;
+            }
+            y = f(y);
+            steps += 1;
+            let d = gcd(x.abs_diff(y), n);
+            if d != 1 {
+                break d;
+            }
+        };
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!()
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub(crate) fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+pub(crate) fn pow_mod(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    let mut exp = exponent;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_small_numbers() {
+        let primes: Vec<u64> = (0..60).filter(|&n| is_prime(n)).collect();
+        assert_eq!(
+            primes,
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59]
+        );
+    }
+
+    #[test]
+    fn test_large_primes() {
+        assert!(is_prime(1039));
+        assert!(is_prime((1 << 61) - 1));
+        assert!(is_prime((1 << 63) - 25));
+        assert!(is_prime(u64::MAX - 58));
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(prime_factors(0), vec![]);
+        assert_eq!(prime_factors(1), vec![]);
+        assert_eq!(prime_factors(72), vec![(2, 3), (3, 2)]);
+        assert_eq!(prime_factors(1039), vec![(1039, 1)]);
+        assert_eq!(prime_factors(65521 * 65519), vec![(65519, 1), (65521, 1)]);
+        assert_eq!(
+            prime_factors(4294967291 * 4294967279),
+            vec![(4294967279, 1), (4294967291, 1)]
+        );
+        assert_eq!(
+            prime_factors(u64::MAX),
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6700417, 1)
+            ]
+        );
+        assert_eq!(prime_factors(1 << 63), vec![(2, 63)]);
+    }
+
+    #[test]
+    fn test_largest_prime_factor() {
+        assert_eq!(largest_prime_factor(1), 1);
+        assert_eq!(largest_prime_factor(72), 3);
+        assert_eq!(largest_prime_factor(2 * 1039 * 1039), 1039);
+    }
+
+    #[test]
+    fn test_divisors() {
+        assert_eq!(divisors(1), vec![1]);
+        assert_eq!(divisors(39), vec![1, 3, 13, 39]);
+        assert_eq!(divisors(72).len(), 12);
+    }
+
+    #[test]
+    fn test_composites() {
+        // Carmichael numbers and a strong pseudoprime to bases 2, 3, 5, 7
+        for n in [561, 41041, 3215031751, 4294967297, u64::MAX] {
+            assert!(!is_prime(n), "{}", n);
+        }
+        assert!(!is_prime(4294967291 * 4294967279));
+    }
+}
@@ -2,3 +2,5 @@
 
 pub mod ec;
 pub mod felt;
+#[cfg(feature = "serde")]
+mod serialization;
@@ -1,4 +1,10 @@
-use rusty_diffie_hellman::{ec::ec_point::ECPoint, felt::felt::Felt};
+use rusty_diffie_hellman::{
+    ec::{
+        ec_point::ECPoint, group_structure::GroupStructure, security::SecurityReport,
+        subgroup::PrimeSubgroup,
+    },
+    felt::felt::Felt,
+};
 
 fn diffie_hellman(g: ECPoint, alice_sk: u64, bob_sk: u64) {
     let alice_public = g * alice_sk;
@@ -71,4 +77,29 @@ fn main() {
     println!("Order of Generator Point 2: {}", order_2);
 
     assert!(order_2 > order_1);
+
+    println!("=====================================");
+    println!("Instead of trying points, look at the group structure");
+
+    let group = GroupStructure::compute(a, b);
+    let (n1, n2) = group.invariants();
+    println!("Group: Z/{} x Z/{}", n1, n2);
+    println!("Cyclic: {}", group.is_cyclic());
+    println!("Generator of Maximal Order: {}", group.generators().1);
+    println!("Subgroup Orders: {:?}", group.subgroup_orders());
+
+    println!("=====================================");
+    println!("Pick a generator of the largest prime order subgroup");
+
+    let subgroup = PrimeSubgroup::find(a, b, &mut rand::thread_rng()).unwrap();
+    println!("Generator Point: {}", subgroup.generator());
+    println!("Order: {}", subgroup.order());
+    println!("Cofactor: {}", subgroup.cofactor());
+
+    diffie_hellman(subgroup.generator(), alice_sk, bob_sk);
+
+    println!("=====================================");
+    println!("Security report for Generator Point 2");
+
+    println!("{}", SecurityReport::analyze(&g2));
 }
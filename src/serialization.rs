@@ -0,0 +1,386 @@
+// Serde support behind the "serde" feature. Human-readable formats (JSON)
+// get objects with hex strings, binary formats get integers and SEC1 bytes.
+// Deserializing validates the same way the constructors do.
+use std::fmt::Formatter;
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    ec::{
+        ec_point::{ECPoint, Sec1Format},
+        named_curves::{named_curve, NamedCurve},
+        weierstrass_curve::WeierstrassCurve,
+    },
+    felt::{felt::Felt, primes::is_prime},
+};
+
+// u64 written as "0x.." in human-readable formats
+#[derive(Clone, Copy)]
+struct Hex(u64);
+
+impl Serialize for Hex {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&format!("{:#x}", self.0))
+        } else {
+            serializer.serialize_u64(self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Hex {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return u64::deserialize(deserializer).map(Hex);
+        }
+        let s = String::deserialize(deserializer)?;
+        let digits = s.strip_prefix("0x").ok_or_else(|| {
+            de::Error::custom(format!("expected a 0x-prefixed hex string, got {:?}", s))
+        })?;
+        u64::from_str_radix(digits, 16)
+            .map(Hex)
+            .map_err(|e| de::Error::custom(format!("invalid hex string {:?}: {}", s, e)))
+    }
+}
+
+// Byte string, serialize_bytes instead of a sequence of u8
+struct Bytes(Vec<u8>);
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BytesVisitor;
+
+        impl<'de> Visitor<'de> for BytesVisitor {
+            type Value = Bytes;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                write!(f, "a byte string")
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Bytes, E> {
+                Ok(Bytes(v.to_vec()))
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Bytes, A::Error> {
+                let mut bytes = Vec::new();
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                Ok(Bytes(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
+
+fn felt<E: de::Error>(value: Hex, modulus: Hex) -> Result<Felt, E> {
+    if modulus.0 == 0 {
+        return Err(E::custom("modulus must be positive"));
+    }
+    if value.0 >= modulus.0 {
+        return Err(E::custom(format!(
+            "{} is not reduced mod {}",
+            value.0, modulus.0
+        )));
+    }
+    Ok(Felt::new(value.0, modulus.0))
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FeltRepr {
+    value: Hex,
+    modulus: Hex,
+}
+
+impl Serialize for Felt {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FeltRepr {
+            value: Hex(self.value()),
+            modulus: Hex(self.modulus()),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Felt {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = FeltRepr::deserialize(deserializer)?;
+        felt(repr.value, repr.modulus)
+    }
+}
+
+// Curve y^2 = x^3 + ax + b (mod p) of an ECPoint, infinity has no x and y
+// Points are only defined over F_p for an odd prime p
+fn odd_prime<E: de::Error>(p: Hex) -> Result<(), E> {
+    if p.0 == 2 || !is_prime(p.0) {
+        return Err(E::custom(format!("modulus {:#x} is not an odd prime", p.0)));
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ECPointRepr {
+    x: Option<Hex>,
+    y: Option<Hex>,
+    a: Hex,
+    b: Hex,
+    p: Hex,
+}
+
+impl Serialize for ECPoint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (a, b, p) = (
+            Hex(self.a().value()),
+            Hex(self.b().value()),
+            Hex(self.a().modulus()),
+        );
+        if !serializer.is_human_readable() {
+            let sec1 = Bytes(self.to_sec1(Sec1Format::Compressed));
+            return (a, b, p, sec1).serialize(serializer);
+        }
+
+        let (x, y) = if self.is_infinity() {
+            (None, None)
+        } else {
+            (Some(Hex(self.x().value())), Some(Hex(self.y().value())))
+        };
+        ECPointRepr { x, y, a, b, p }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ECPoint {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            let (a, b, p, sec1) = <(Hex, Hex, Hex, Bytes)>::deserialize(deserializer)?;
+            odd_prime(p)?;
+            let (a, b) = (felt(a, p)?, felt(b, p)?);
+            return ECPoint::from_sec1(&sec1.0, a, b).map_err(de::Error::custom);
+        }
+
+        let repr = ECPointRepr::deserialize(deserializer)?;
+        odd_prime(repr.p)?;
+        let (a, b) = (felt(repr.a, repr.p)?, felt(repr.b, repr.p)?);
+        match (repr.x, repr.y) {
+            (None, None) => Ok(ECPoint::infinity(a, b)),
+            (Some(x), Some(y)) => {
+                ECPoint::new(felt(x, repr.p)?, felt(y, repr.p)?, a, b).map_err(de::Error::custom)
+            }
+            _ => Err(de::Error::custom(
+                "x and y must both be set or both be null",
+            )),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WeierstrassCurveRepr {
+    a1: Hex,
+    a2: Hex,
+    a3: Hex,
+    a4: Hex,
+    a6: Hex,
+    p: Hex,
+}
+
+impl Serialize for WeierstrassCurve {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let [a1, a2, a3, a4, a6] = self.coefficients().map(|a| Hex(a.value()));
+        let p = Hex(self.modulus());
+        WeierstrassCurveRepr {
+            a1,
+            a2,
+            a3,
+            a4,
+            a6,
+            p,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for WeierstrassCurve {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = WeierstrassCurveRepr::deserialize(deserializer)?;
+        let p = repr.p;
+        WeierstrassCurve::new(
+            felt(repr.a1, p)?,
+            felt(repr.a2, p)?,
+            felt(repr.a3, p)?,
+            felt(repr.a4, p)?,
+            felt(repr.a6, p)?,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+// Named curves are referred to by name, the parameters come from the registry
+impl Serialize for NamedCurve {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for NamedCurve {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        named_curve(&name)
+            .copied()
+            .ok_or_else(|| de::Error::custom(format!("unknown curve {}", name)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn curve_point() -> ECPoint {
+        ECPoint::new(
+            Felt::new(18, 37),
+            Felt::new(26, 37),
+            Felt::new(3, 37),
+            Felt::new(7, 37),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_felt_json() {
+        let f = Felt::new(255, 257);
+        let json = serde_json::to_string(&f).unwrap();
+        assert_eq!(json, r#"{"value":"0xff","modulus":"0x101"}"#);
+        assert_eq!(serde_json::from_str::<Felt>(&json).unwrap(), f);
+    }
+
+    #[test]
+    fn test_felt_json_validation() {
+        for json in [
+            r#"{"value":"0x101","modulus":"0x101"}"#,
+            r#"{"value":"0x1","modulus":"0x0"}"#,
+            r#"{"value":"1","modulus":"0x7"}"#,
+            r#"{"value":"0x1","modulus":"0x7","extra":"0x1"}"#,
+        ] {
+            assert!(serde_json::from_str::<Felt>(json).is_err(), "{}", json);
+        }
+    }
+
+    #[test]
+    fn test_ec_point_json() {
+        let p = curve_point();
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(
+            json,
+            r#"{"x":"0x12","y":"0x1a","a":"0x3","b":"0x7","p":"0x25"}"#
+        );
+        assert_eq!(serde_json::from_str::<ECPoint>(&json).unwrap(), p);
+
+        let infinity = ECPoint::infinity(p.a(), p.b());
+        let json = serde_json::to_string(&infinity).unwrap();
+        assert_eq!(
+            json,
+            r#"{"x":null,"y":null,"a":"0x3","b":"0x7","p":"0x25"}"#
+        );
+        assert_eq!(serde_json::from_str::<ECPoint>(&json).unwrap(), infinity);
+    }
+
+    #[test]
+    fn test_ec_point_json_rejects_off_curve_points() {
+        let json = r#"{"x":"0x12","y":"0x1b","a":"0x3","b":"0x7","p":"0x25"}"#;
+        let error = serde_json::from_str::<ECPoint>(json).unwrap_err();
+        assert!(error.to_string().contains("is not on the curve"));
+
+        let json = r#"{"x":"0x12","y":null,"a":"0x3","b":"0x7","p":"0x25"}"#;
+        assert!(serde_json::from_str::<ECPoint>(json).is_err());
+    }
+
+    fn cbor<T: Serialize>(value: &T) -> Vec<u8> {
+        let mut bytes = vec![];
+        ciborium::into_writer(value, &mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_ec_point_binary() {
+        let p = curve_point();
+        let bytes = cbor(&p);
+        assert_eq!(
+            ciborium::from_reader::<ECPoint, _>(bytes.as_slice()).unwrap(),
+            p
+        );
+
+        // The point is stored as compressed SEC1 at the end
+        assert!(bytes.ends_with(&[0x42, 0x02, 0x12]));
+
+        let infinity = ECPoint::infinity(p.a(), p.b());
+        let bytes = cbor(&infinity);
+        assert_eq!(
+            ciborium::from_reader::<ECPoint, _>(bytes.as_slice()).unwrap(),
+            infinity
+        );
+    }
+
+    #[test]
+    fn test_ec_point_binary_rejects_invalid_sec1() {
+        let p = curve_point();
+        let mut bytes = cbor(&p);
+        // x = 0x11 is not the abscissa of a point on the curve
+        *bytes.last_mut().unwrap() = 0x11;
+        assert!(ciborium::from_reader::<ECPoint, _>(bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_ec_point_rejects_moduli_that_are_not_odd_primes() {
+        // (a, b, p, sec1) for the point (0, 1) on y^2 = x^3 + 1 over F_2
+        let bytes = cbor(&(Hex(0), Hex(1), Hex(2), Bytes(vec![0x03, 0x00])));
+        let error = ciborium::from_reader::<ECPoint, _>(bytes.as_slice()).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("modulus 0x2 is not an odd prime"));
+
+        let json = r#"{"x":"0x0","y":"0x1","a":"0x0","b":"0x1","p":"0xf"}"#;
+        let error = serde_json::from_str::<ECPoint>(json).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("modulus 0xf is not an odd prime"));
+    }
+
+    #[test]
+    fn test_weierstrass_curve_json() {
+        let one = Felt::new(1, 2);
+        let zero = Felt::new(0, 2);
+        let e = WeierstrassCurve::new(one, zero, zero, zero, one).unwrap();
+        let json = serde_json::to_string(&e).unwrap();
+        assert_eq!(
+            json,
+            r#"{"a1":"0x1","a2":"0x0","a3":"0x0","a4":"0x0","a6":"0x1","p":"0x2"}"#
+        );
+        assert_eq!(serde_json::from_str::<WeierstrassCurve>(&json).unwrap(), e);
+
+        // y^2 = x^3 is singular
+        let json = r#"{"a1":"0x0","a2":"0x0","a3":"0x0","a4":"0x0","a6":"0x0","p":"0x7"}"#;
+        assert!(serde_json::from_str::<WeierstrassCurve>(json).is_err());
+    }
+
+    #[test]
+    fn test_named_curve_json() {
+        let curve = *named_curve("P-256").unwrap();
+        assert_eq!(serde_json::to_string(&curve).unwrap(), r#""P-256""#);
+        assert_eq!(
+            serde_json::from_str::<NamedCurve>(r#""secp256r1""#).unwrap(),
+            curve
+        );
+        assert!(serde_json::from_str::<NamedCurve>(r#""P-257""#).is_err());
+    }
+}